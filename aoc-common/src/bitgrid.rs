@@ -0,0 +1,116 @@
+use crate::Position;
+
+/// A set of positions on a `width` x `height` grid, stored as one bit per
+/// cell. It has the `contains`/`insert`/`remove` API of a
+/// `HashSet<Position>`, but every operation is an indexed bit access.
+///
+/// Positions outside of the grid are never contained; inserting one panics.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitGrid {
+    pub width: usize,
+    pub height: usize,
+    bits: Vec<u64>,
+}
+
+impl BitGrid {
+    /// An empty set on a `width` x `height` grid.
+    pub fn new(width: usize, height: usize) -> BitGrid {
+        BitGrid {
+            width,
+            height,
+            bits: vec![0; (width * height).div_ceil(64)],
+        }
+    }
+
+    fn index_of(&self, (x, y): Position) -> Option<usize> {
+        let in_bounds = x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height;
+        in_bounds.then(|| y as usize * self.width + x as usize)
+    }
+
+    /// Whether `pos` is in the set.
+    pub fn contains(&self, pos: Position) -> bool {
+        self.index_of(pos)
+            .is_some_and(|i| self.bits[i / 64] & (1 << (i % 64)) != 0)
+    }
+
+    /// Adds `pos` to the set. Returns whether it was not in the set yet.
+    pub fn insert(&mut self, pos: Position) -> bool {
+        let i = self
+            .index_of(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", pos));
+        let word = &mut self.bits[i / 64];
+        let new = *word & (1 << (i % 64)) == 0;
+        *word |= 1 << (i % 64);
+        new
+    }
+
+    /// Removes `pos` from the set. Returns whether it was in the set.
+    pub fn remove(&mut self, pos: Position) -> bool {
+        match self.index_of(pos) {
+            Some(i) => {
+                let word = &mut self.bits[i / 64];
+                let present = *word & (1 << (i % 64)) != 0;
+                *word &= !(1 << (i % 64));
+                present
+            }
+            None => false,
+        }
+    }
+
+    /// Removes all positions.
+    pub fn clear(&mut self) {
+        self.bits.fill(0);
+    }
+
+    /// The number of positions in the set.
+    pub fn len(&self) -> usize {
+        self.bits
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|&word| word == 0)
+    }
+
+    /// The positions in the set, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = Position> + '_ {
+        let width = self.width as i64;
+        (0..self.width * self.height)
+            .filter(|i| self.bits[i / 64] & (1 << (i % 64)) != 0)
+            .map(move |i| (i as i64 % width, i as i64 / width))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_contains_remove() {
+        let mut set = BitGrid::new(10, 7);
+        assert!(set.is_empty());
+        assert!(set.insert((3, 2)));
+        assert!(!set.insert((3, 2)));
+        assert!(set.insert((9, 6)));
+        assert!(set.contains((3, 2)));
+        assert!(!set.contains((2, 3)));
+        assert!(!set.contains((-1, 0)));
+        assert!(!set.contains((10, 0)));
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![(3, 2), (9, 6)]);
+        assert!(set.remove((3, 2)));
+        assert!(!set.remove((3, 2)));
+        assert!(!set.remove((10, 0)));
+        assert_eq!(set.len(), 1);
+        set.clear();
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "outside of the grid")]
+    fn test_insert_out_of_bounds() {
+        BitGrid::new(2, 2).insert((2, 0));
+    }
+}
@@ -0,0 +1,134 @@
+use crate::Position;
+use std::fmt;
+
+/// One of the four orthogonal directions on a grid with `y` growing downwards,
+/// so that north is up and east is right.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// All directions, clockwise from north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Iterates over all directions, clockwise from north.
+    pub fn all() -> impl Iterator<Item = Direction> {
+        Direction::ALL.into_iter()
+    }
+
+    /// The direction after turning 90 degrees clockwise.
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    /// The direction after turning 90 degrees counterclockwise.
+    pub fn turn_left(self) -> Direction {
+        self.turn_right().reverse()
+    }
+
+    /// The opposite direction.
+    pub fn reverse(self) -> Direction {
+        self.turn_right().turn_right()
+    }
+
+    /// The `(dx, dy)` offset of one step in this direction.
+    pub fn delta(self) -> Position {
+        match self {
+            Direction::North => (0, -1),
+            Direction::East => (1, 0),
+            Direction::South => (0, 1),
+            Direction::West => (-1, 0),
+        }
+    }
+
+    /// The position one step from `pos` in this direction.
+    pub fn step(self, (x, y): Position) -> Position {
+        let (dx, dy) = self.delta();
+        (x + dx, y + dy)
+    }
+
+    /// Whether this is east or west.
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::East | Direction::West)
+    }
+
+    /// Parses one of the arrows `^`, `>`, `v` and `<`.
+    pub fn from_arrow(arrow: char) -> Option<Direction> {
+        match arrow {
+            '^' => Some(Direction::North),
+            '>' => Some(Direction::East),
+            'v' => Some(Direction::South),
+            '<' => Some(Direction::West),
+            _ => None,
+        }
+    }
+
+    /// The arrow pointing in this direction, the inverse of `from_arrow`.
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::North => '^',
+            Direction::East => '>',
+            Direction::South => 'v',
+            Direction::West => '<',
+        }
+    }
+}
+
+/// Displays the direction as its arrow.
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.arrow())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turn_right() {
+        assert_eq!(Direction::North.turn_right().delta(), (1, 0));
+        assert_eq!(Direction::East.turn_right().delta(), (0, 1));
+        assert_eq!(Direction::South.turn_right().delta(), (-1, 0));
+        assert_eq!(Direction::West.turn_right().delta(), (0, -1));
+    }
+
+    #[test]
+    fn test_turns() {
+        for dir in Direction::all() {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_left().turn_left(), dir.reverse());
+            assert_ne!(dir.reverse(), dir);
+            let (dx, dy) = dir.delta();
+            assert_eq!(dir.reverse().delta(), (-dx, -dy));
+        }
+        // turning left from north faces west, i.e. towards smaller x
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::East.step((2, 3)), (3, 3));
+    }
+
+    #[test]
+    fn test_arrows() {
+        let arrows: String = Direction::all().map(Direction::arrow).collect();
+        assert_eq!(arrows, "^>v<");
+        for dir in Direction::all() {
+            assert_eq!(Direction::from_arrow(dir.arrow()), Some(dir));
+        }
+        assert_eq!(Direction::from_arrow('x'), None);
+        assert_eq!(Direction::South.to_string(), "v");
+    }
+}
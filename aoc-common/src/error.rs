@@ -0,0 +1,117 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// A malformed puzzle input, with the position of the problem.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The day whose input was parsed, if known.
+    pub day: Option<u8>,
+    /// Line number, starting at 1.
+    pub line: usize,
+    /// Column number in characters, starting at 1.
+    pub column: usize,
+    /// What the parser expected to find at that position.
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: impl Into<String>) -> Self {
+        ParseError {
+            day: None,
+            line,
+            column,
+            expected: expected.into(),
+        }
+    }
+
+    /// Attributes the error to `day`, e.g. for errors raised by a shared parser.
+    pub fn in_day(self, day: u8) -> Self {
+        ParseError {
+            day: Some(day),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "Day {}, ", day)?;
+        }
+        write!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// A numbered line of puzzle input, used to report parse errors at the
+/// position where they occur.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub day: u8,
+    /// Line number, starting at 1.
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// The lines of `input`, numbered from 1.
+    pub fn all(day: u8, input: &'a str) -> impl Iterator<Item = Line<'a>> {
+        input.lines().enumerate().map(move |(i, text)| Line {
+            day,
+            number: i + 1,
+            text,
+        })
+    }
+
+    /// An error at character `column` (starting at 1) of this line.
+    pub fn error_at_column(&self, column: usize, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, column, expected).in_day(self.day)
+    }
+
+    /// An error at `part`, which must be a substring slice of this line.
+    pub fn error_at(&self, part: &str, expected: impl Into<String>) -> ParseError {
+        let offset = part.as_ptr() as usize - self.text.as_ptr() as usize;
+        self.error_at_column(self.text[..offset].chars().count() + 1, expected)
+    }
+
+    /// An error right after the last character of this line.
+    pub fn error_at_end(&self, expected: impl Into<String>) -> ParseError {
+        self.error_at_column(self.text.chars().count() + 1, expected)
+    }
+
+    /// Parses `part`, a substring slice of this line, reporting `expected` on failure.
+    pub fn parse<T: FromStr>(&self, part: &str, expected: &str) -> Result<T, ParseError> {
+        part.parse().map_err(|_| self.error_at(part, expected))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let err = ParseError::new(3, 7, "a number");
+        assert_eq!(err.to_string(), "line 3, column 7: expected a number");
+        assert_eq!(
+            err.in_day(5).to_string(),
+            "Day 5, line 3, column 7: expected a number"
+        );
+    }
+
+    #[test]
+    fn test_line_positions() {
+        let lines: Vec<Line> = Line::all(7, "1: 2 3\n4: x 5").collect();
+        assert_eq!(lines[1].number, 2);
+        let field = lines[1].text.split_whitespace().nth(1).unwrap();
+        let err = lines[1].parse::<i64>(field, "a number").unwrap_err();
+        assert_eq!(err, ParseError::new(2, 4, "a number").in_day(7));
+        assert_eq!(lines[0].error_at_end("`:`").column, 7);
+    }
+}
@@ -0,0 +1,295 @@
+use crate::{ParseError, Position};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+/// The offsets of the 4 orthogonal neighbours, clockwise from north.
+const NEIGHBOURS4: [Position; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The offsets of the 8 neighbours including diagonals, clockwise from north.
+const NEIGHBOURS8: [Position; 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A rectangular grid stored row by row.
+///
+/// Cells are addressed by `(x, y)` positions with `y` growing downwards.
+/// Positions outside of the grid are allowed wherever a position is taken:
+/// `get` returns `None` for them and the neighbour iterators skip them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T = char> {
+    pub width: usize,
+    pub height: usize,
+    pub data: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, data: Vec<T>) -> Grid<T> {
+        assert_eq!(
+            data.len(),
+            width * height,
+            "grid data does not match its size"
+        );
+        Grid {
+            width,
+            height,
+            data,
+        }
+    }
+
+    pub fn new_from_vecs(width: usize, height: usize, data: Vec<Vec<T>>) -> Grid<T> {
+        let data: Vec<T> = data.into_iter().flatten().collect();
+        Grid::new(width, height, data)
+    }
+
+    /// A grid with every cell set to `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Parses a grid with one line per row, mapping every character to a cell
+    /// with `cell`. A character that `cell` maps to `None` is reported as a
+    /// `ParseError` saying that `expected` was expected instead.
+    pub fn parse_with(
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut data = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for (y, line) in input.lines().enumerate() {
+            let mut line_width = 0;
+            for (x, ch) in line.chars().enumerate() {
+                if width.is_some_and(|width| x >= width) {
+                    break;
+                }
+                data.push(cell(ch).ok_or_else(|| ParseError::new(y + 1, x + 1, expected))?);
+                line_width += 1;
+            }
+            let width = *width.get_or_insert(line_width);
+            if width == 0 {
+                return Err(ParseError::new(1, 1, "a non-empty grid"));
+            }
+            if line.chars().count() != width {
+                return Err(ParseError::new(
+                    y + 1,
+                    line_width + 1,
+                    format!("{} characters per line", width),
+                ));
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) => Ok(Grid::new(width, height, data)),
+            None => Err(ParseError::new(1, 1, "a non-empty grid")),
+        }
+    }
+
+    /// Whether `pos` lies within the grid.
+    pub fn in_bounds(&self, (x, y): Position) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    fn index_of(&self, pos: Position) -> Option<usize> {
+        self.in_bounds(pos)
+            .then(|| pos.1 as usize * self.width + pos.0 as usize)
+    }
+
+    /// The cell at `pos`, or `None` if `pos` is outside of the grid.
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.data[i])
+    }
+
+    /// The cell at `pos`, or `None` if `pos` is outside of the grid.
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.data[i])
+    }
+
+    /// The cells of row `y`.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    /// The rows from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.data.chunks(self.width)
+    }
+
+    /// The cells of column `x` from top to bottom.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is outside of the grid", x);
+        self.data[x..].iter().step_by(self.width)
+    }
+
+    /// All positions of the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width as i64;
+        (0..self.data.len() as i64).map(move |i| (i % width, i / width))
+    }
+
+    /// All cells of the grid with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.data.iter())
+    }
+
+    /// The position of the first cell, row by row, that satisfies `predicate`.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        let i = self.data.iter().position(predicate)?;
+        Some(((i % self.width) as i64, (i / self.width) as i64))
+    }
+
+    /// The orthogonal neighbours of `pos` that lie within the grid.
+    pub fn neighbours4(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(pos, &NEIGHBOURS4)
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos` that lie within the grid.
+    pub fn neighbours8(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(pos, &NEIGHBOURS8)
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        (x, y): Position,
+        offsets: &'static [Position],
+    ) -> impl Iterator<Item = Position> + 'a {
+        offsets
+            .iter()
+            .map(move |(dx, dy)| (x + dx, y + dy))
+            .filter(|&pos| self.in_bounds(pos))
+    }
+
+    /// A grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.data.iter().map(f).collect())
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", pos))
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse_with(s, "any character", Some)
+    }
+}
+
+/// One line per row, without a trailing newline.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        let grid: Grid = "AB\nCD\n".parse().unwrap();
+        assert_eq!((grid.width, grid.height), (2, 2));
+        assert_eq!(grid.get((0, 1)), Some(&'C'));
+        assert_eq!(grid.get((2, 1)), None);
+        assert_eq!(grid.get((0, -1)), None);
+        assert_eq!(grid.to_string(), "AB\nCD");
+    }
+
+    #[test]
+    fn test_from_str_ragged() {
+        let err = "AB\nC\nDE".parse::<Grid>().unwrap_err();
+        assert_eq!(err, ParseError::new(2, 2, "2 characters per line"));
+        let err = "AB\nCDE".parse::<Grid>().unwrap_err();
+        assert_eq!(err, ParseError::new(2, 3, "2 characters per line"));
+        let err = "".parse::<Grid>().unwrap_err();
+        assert_eq!(err, ParseError::new(1, 1, "a non-empty grid"));
+    }
+
+    #[test]
+    fn test_parse_with() {
+        let grid = Grid::parse_with("#.\n.#", "`#` or `.`", |ch| match ch {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+        .unwrap();
+        assert_eq!(grid.data, vec![true, false, false, true]);
+        let err = Grid::parse_with("#.\n.x", "`#` or `.`", |ch| (ch != 'x').then_some(ch));
+        assert_eq!(err, Err(ParseError::new(2, 2, "`#` or `.`")));
+    }
+
+    #[test]
+    fn test_get_mut_and_index() {
+        let mut grid = Grid::filled(3, 2, 0);
+        *grid.get_mut((2, 1)).unwrap() = 5;
+        grid[(0, 0)] = 1;
+        assert!(grid.get_mut((3, 0)).is_none());
+        assert_eq!(grid.data, vec![1, 0, 0, 0, 0, 5]);
+        assert_eq!(grid.position(|&cell| cell == 5), Some((2, 1)));
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid: Grid = "ABC\nDEF".parse().unwrap();
+        assert_eq!(grid.row(1), &['D', 'E', 'F']);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(1).collect::<String>(), "BE");
+        let cells: Vec<(Position, char)> = grid.iter().map(|(pos, &c)| (pos, c)).collect();
+        assert_eq!(cells[4], ((1, 1), 'E'));
+    }
+
+    #[test]
+    fn test_in_bounds() {
+        let grid = Grid::filled(3, 2, ());
+        assert!(grid.in_bounds((0, 0)));
+        assert!(grid.in_bounds((2, 1)));
+        assert!(!grid.in_bounds((3, 1)));
+        assert!(!grid.in_bounds((0, 2)));
+        assert!(!grid.in_bounds((-1, 0)));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::filled(3, 3, ());
+        let corner: Vec<Position> = grid.neighbours4((0, 0)).collect();
+        assert_eq!(corner, vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        let edge: Vec<Position> = grid.neighbours8((2, 1)).collect();
+        assert_eq!(edge, vec![(2, 0), (2, 2), (1, 2), (1, 1), (1, 0)]);
+    }
+}
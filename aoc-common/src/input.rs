@@ -0,0 +1,154 @@
+//! Loading of puzzle inputs at runtime.
+
+use std::convert::Infallible;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// The default input location of a day. `{day}` is replaced by the two-digit
+/// day number.
+pub const DEFAULT_PATTERN: &str = "day{day}a/input.txt";
+
+/// Where a puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// The input of `day` according to `pattern`, see [`DEFAULT_PATTERN`].
+    pub fn for_day(day: u8, pattern: &str) -> Source {
+        Source::File(PathBuf::from(
+            pattern.replace("{day}", &format!("{:02}", day)),
+        ))
+    }
+
+    /// Reads the whole input.
+    pub fn read(&self) -> Result<String, InputError> {
+        let result = match self {
+            Source::Stdin => {
+                let mut content = String::new();
+                io::stdin().read_to_string(&mut content).map(|_| content)
+            }
+            Source::File(path) => std::fs::read_to_string(path),
+        };
+        result.map_err(|error| InputError {
+            source: self.clone(),
+            error,
+        })
+    }
+}
+
+/// `-` stands for stdin, anything else is a file path.
+impl FromStr for Source {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(if s == "-" {
+            Source::Stdin
+        } else {
+            Source::File(PathBuf::from(s))
+        })
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "stdin"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// An input that could not be read.
+#[derive(Debug)]
+pub struct InputError {
+    pub source: Source,
+    pub error: io::Error,
+}
+
+impl InputError {
+    pub fn is_not_found(&self) -> bool {
+        self.error.kind() == io::ErrorKind::NotFound
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_not_found() {
+            write!(f, "input file {} does not exist", self.source)
+        } else {
+            write!(f, "cannot read {}: {}", self.source, self.error)
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// The real input of `day`, for tests and benchmarks.
+///
+/// The input is looked up like the runner does, using `AOC_INPUTS` or
+/// [`DEFAULT_PATTERN`], but relative to the workspace root rather than the
+/// current directory. The real inputs are not committed, so a missing input
+/// gives `None` and a note on stderr, and callers skip their checks.
+pub fn puzzle_input(day: u8) -> Option<String> {
+    let pattern = std::env::var("AOC_INPUTS").unwrap_or_else(|_| DEFAULT_PATTERN.to_string());
+    let pattern = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .join(pattern);
+    match Source::for_day(day, &pattern.to_string_lossy()).read() {
+        Ok(input) => Some(input),
+        Err(err) if err.is_not_found() => {
+            eprintln!("skipped: {}", err);
+            None
+        }
+        Err(err) => panic!("{}", err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_day() {
+        assert_eq!(
+            Source::for_day(6, DEFAULT_PATTERN),
+            Source::File(PathBuf::from("day06a/input.txt"))
+        );
+        assert_eq!(
+            Source::for_day(12, "inputs/{day}.txt"),
+            Source::File(PathBuf::from("inputs/12.txt"))
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("-".parse(), Ok(Source::Stdin));
+        assert_eq!(
+            "day01a/input.txt".parse(),
+            Ok(Source::File(PathBuf::from("day01a/input.txt")))
+        );
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let err = Source::File(PathBuf::from("does/not/exist.txt"))
+            .read()
+            .unwrap_err();
+        assert!(err.is_not_found());
+        assert_eq!(
+            err.to_string(),
+            "input file does/not/exist.txt does not exist"
+        );
+    }
+}
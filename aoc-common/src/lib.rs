@@ -0,0 +1,17 @@
+//! Helpers shared between the solutions of the individual days.
+
+pub mod bitgrid;
+pub mod direction;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod solution;
+
+pub use bitgrid::BitGrid;
+pub use direction::Direction;
+pub use error::{Line, ParseError};
+pub use grid::Grid;
+pub use solution::Solution;
+
+/// A position `(x, y)` on a grid, with `y` growing downwards.
+pub type Position = (i64, i64);
@@ -0,0 +1,20 @@
+use crate::ParseError;
+use std::fmt::Display;
+
+/// The solution of one day: a parse step shared by both parts, followed by the
+/// two parts working on the parsed input.
+pub trait Solution {
+    /// The puzzle input after parsing.
+    type Input;
+
+    /// Parses the raw puzzle input.
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Solves part 1.
+    fn part1(input: &Self::Input) -> impl Display;
+
+    /// Solves part 2, or returns `None` if part 2 has not been solved yet.
+    fn part2(_input: &Self::Input) -> Option<impl Display> {
+        None::<u64>
+    }
+}
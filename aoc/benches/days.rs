@@ -0,0 +1,117 @@
+//! Benchmarks parsing and both parts of every day, on the example input, on a
+//! generated input of real size and, when it is present, on the real input.
+//!
+//! Run `cargo bench -p aoc -- day06/generated` to benchmark one day and input.
+
+mod inputs;
+
+use aoc_common::input::puzzle_input;
+use aoc_common::{Direction, Solution};
+use criterion::{criterion_group, criterion_main, Criterion, SamplingMode};
+use day01b::{similarity_with, total_distance_with, Strategy};
+use day06b::Execution;
+use std::hint::black_box;
+use std::time::Duration;
+
+/// The inputs to benchmark `day` on, by name.
+fn inputs(day: u8) -> Vec<(&'static str, String)> {
+    let mut inputs = vec![
+        ("example", inputs::example(day)),
+        ("generated", inputs::generated(day)),
+    ];
+    if let Some(input) = puzzle_input(day) {
+        inputs.push(("input", input));
+    }
+    inputs
+}
+
+fn bench_day<S: Solution>(c: &mut Criterion, day: u8) {
+    for (name, input) in inputs(day) {
+        let input = input.trim_end();
+        let mut group = c.benchmark_group(format!("day{:02}/{}", day, name));
+        if name != "example" {
+            // a single run of some parts takes up to a second
+            group
+                .sampling_mode(SamplingMode::Flat)
+                .sample_size(10)
+                .warm_up_time(Duration::from_millis(500));
+        }
+        group.bench_function("parse", |b| b.iter(|| S::parse(black_box(input))));
+        let parsed = match S::parse(input) {
+            Ok(parsed) => parsed,
+            Err(err) => panic!("{} input: {}", name, err),
+        };
+        group.bench_function("part1", |b| {
+            b.iter(|| S::part1(black_box(&parsed)).to_string())
+        });
+        if S::part2(&parsed).is_some() {
+            group.bench_function("part2", |b| {
+                b.iter(|| S::part2(black_box(&parsed)).map(|answer| answer.to_string()))
+            });
+        }
+        group.finish();
+    }
+}
+
+/// Compares the sorting and counting strategies of day 1 on a list of a
+/// million lines, far longer than the puzzle input.
+fn day01_strategies(c: &mut Criterion) {
+    let input = inputs::day01(&mut inputs::Rng::new(1), 1_000_000);
+    let (l, r) = day01b::Day01::parse(&input).unwrap();
+    let mut group = c.benchmark_group("day01/strategies");
+    group.sample_size(10);
+    for strategy in [Strategy::Comparison, Strategy::Linear] {
+        group.bench_function(format!("part1/{:?}", strategy), |b| {
+            b.iter(|| total_distance_with(&mut l.clone(), &mut r.clone(), strategy))
+        });
+        group.bench_function(format!("part2/{:?}", strategy), |b| {
+            b.iter(|| similarity_with(black_box(&l), black_box(&r), strategy))
+        });
+    }
+    group.finish();
+}
+
+/// Compares part 2 of day 6 walking every candidate obstacle cell by cell
+/// with walking it segment by segment through the jump table, one candidate
+/// after another and in parallel, on a 130x130 lab like the real ones.
+fn day06_jump_table(c: &mut Criterion) {
+    let input = inputs::day06(&mut inputs::Rng::new(6), 130);
+    let (obstacles, start) = day06b::Day06::parse(&input).unwrap();
+    let north = Direction::North;
+    let expected = day06b::obstacles_for_cycle_stepping(start, north, obstacles.clone());
+    assert_eq!(
+        day06b::obstacles_for_cycle(start, north, obstacles.clone()),
+        expected
+    );
+    let parallel = day06b::obstacles_for_cycle_with(start, north, &obstacles, Execution::Parallel);
+    assert_eq!(parallel, expected);
+    let mut group = c.benchmark_group("day06/jump_table");
+    group.sample_size(10);
+    group.bench_function("stepping", |b| {
+        b.iter(|| day06b::obstacles_for_cycle_stepping(start, north, obstacles.clone()))
+    });
+    group.bench_function("jumping", |b| {
+        b.iter(|| day06b::obstacles_for_cycle(start, north, obstacles.clone()))
+    });
+    group.bench_function("jumping_parallel", |b| {
+        b.iter(|| day06b::obstacles_for_cycle_with(start, north, &obstacles, Execution::Parallel))
+    });
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<day01b::Day01>(c, 1);
+    bench_day::<day02b::Day02>(c, 2);
+    bench_day::<day05b::Day05>(c, 5);
+    bench_day::<day06b::Day06>(c, 6);
+    bench_day::<day07b::Day07>(c, 7);
+    bench_day::<day08b::Day08>(c, 8);
+    bench_day::<day09a::Day09>(c, 9);
+    bench_day::<day11b::Day11>(c, 11);
+    bench_day::<day12b::Day12>(c, 12);
+    bench_day::<day15b::Day15>(c, 15);
+    bench_day::<day16a::Day16>(c, 16);
+}
+
+criterion_group!(benches, days, day01_strategies, day06_jump_table);
+criterion_main!(benches);
@@ -0,0 +1,413 @@
+//! Inputs for the benchmarks: the published example of every day, and
+//! generated inputs of the same size and shape as the real puzzle inputs,
+//! which are not committed.
+
+use aoc_common::Direction;
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+/// A small deterministic PRNG (SplitMix64), so that the generated inputs are
+/// the same on every run and benchmark results stay comparable.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `lo..=hi`.
+    pub fn between(&mut self, lo: u64, hi: u64) -> u64 {
+        lo + self.next_u64() % (hi - lo + 1)
+    }
+
+    /// `true` with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64 > 1.0 - p
+    }
+
+    pub fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.between(0, items.len() as u64 - 1) as usize]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.between(0, i as u64) as usize);
+        }
+    }
+}
+
+/// The example input given in the puzzle description of `day`, from
+/// `dayNNa/examples/example.txt`.
+pub fn example(day: u8) -> String {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .join(format!("day{:02}a/examples/example.txt", day));
+    fs::read_to_string(&path).unwrap_or_else(|err| panic!("{}: {}", path.display(), err))
+}
+
+/// A generated input for `day` with the size and shape of a real input.
+pub fn generated(day: u8) -> String {
+    let mut rng = Rng::new(0x2024_0000 + day as u64);
+    match day {
+        1 => day01(&mut rng, 1000),
+        2 => day02(&mut rng),
+        5 => day05(&mut rng),
+        6 => day06(&mut rng, 130),
+        7 => day07(&mut rng),
+        8 => day08(&mut rng),
+        9 => day09(&mut rng),
+        11 => day11(&mut rng),
+        12 => day12(&mut rng),
+        15 => day15(&mut rng),
+        16 => day16(&mut rng),
+        _ => panic!("no generator for day {}", day),
+    }
+}
+
+/// `lines` pairs of five-digit location IDs, with about half of the right list
+/// repeating IDs of the left list. The real inputs have 1000.
+pub fn day01(rng: &mut Rng, lines: usize) -> String {
+    let left: Vec<u64> = (0..lines).map(|_| rng.between(10000, 99999)).collect();
+    let mut out = String::new();
+    for &l in &left {
+        let r = if rng.chance(0.5) {
+            rng.pick(&left)
+        } else {
+            rng.between(10000, 99999)
+        };
+        out += &format!("{}   {}\n", l, r);
+    }
+    out
+}
+
+/// 1000 reports of 5 to 8 levels that mostly change by 1 to 3 in one
+/// direction, with an occasional bad step.
+fn day02(rng: &mut Rng) -> String {
+    let mut out = String::new();
+    for _ in 0..1000 {
+        let increasing = rng.chance(0.5);
+        let mut level = if increasing {
+            rng.between(1, 50) as i64
+        } else {
+            rng.between(50, 99) as i64
+        };
+        let mut levels = vec![level];
+        for _ in 1..rng.between(5, 8) {
+            let step = if rng.chance(0.1) {
+                rng.pick(&[0, 4, 5, -1, -2])
+            } else {
+                rng.between(1, 3) as i64
+            };
+            level += if increasing { step } else { -step };
+            levels.push(level);
+        }
+        let levels: Vec<String> = levels.iter().map(|l| l.to_string()).collect();
+        out += &levels.join(" ");
+        out.push('\n');
+    }
+    out
+}
+
+/// 49 pages arranged in a circle where every page must come before the next
+/// 24 pages, which gives 1176 rules whose graph contains cycles like the real
+/// one. Each of the 200 updates is taken from 25 consecutive pages of the
+/// circle, so it has a valid order, and half of them are shuffled.
+fn day05(rng: &mut Rng) -> String {
+    let mut pages: Vec<u64> = (10..100).collect();
+    rng.shuffle(&mut pages);
+    pages.truncate(49);
+    let n = pages.len();
+
+    let mut rules = Vec::new();
+    for i in 0..n {
+        for d in 1..=n / 2 {
+            rules.push((pages[i], pages[(i + d) % n]));
+        }
+    }
+    rng.shuffle(&mut rules);
+    let mut out = String::new();
+    for (x, y) in rules {
+        out += &format!("{}|{}\n", x, y);
+    }
+    out.push('\n');
+
+    for _ in 0..200 {
+        let start = rng.between(0, n as u64 - 1) as usize;
+        let mut arc: Vec<usize> = (0..=n / 2).collect();
+        rng.shuffle(&mut arc);
+        arc.truncate(2 * rng.between(2, 11) as usize + 1);
+        if rng.chance(0.5) {
+            arc.sort_unstable();
+        }
+        let update: Vec<String> = arc
+            .iter()
+            .map(|i| pages[(start + i) % n].to_string())
+            .collect();
+        out += &update.join(",");
+        out.push('\n');
+    }
+    out
+}
+
+/// A `size`×`size` lab in which the guard walks an outward spiral with rings 2
+/// to 4 cells apart before leaving, so that like in the real inputs the guard
+/// visits about a third of the lab. Further obstacles are spread over about 5%
+/// of the cells the guard does not visit.
+pub fn day06(rng: &mut Rng, size: usize) -> String {
+    let size = size as i64;
+    let inbounds = |(x, y): (i64, i64)| x >= 0 && y >= 0 && x < size && y < size;
+    let mut cells = vec![vec!['.'; size as usize]; size as usize];
+    let start = (
+        size / 2 + rng.between(0, 10) as i64 - 5,
+        size / 2 + rng.between(0, 10) as i64 - 5,
+    );
+    cells[start.1 as usize][start.0 as usize] = '^';
+
+    // lay out the legs of the spiral, each longer than the previous parallel
+    // leg, until one of them leaves the lab
+    let (mut pos, mut dir) = (start, Direction::North);
+    let mut lengths = [0, 0];
+    'spiral: for leg in 0.. {
+        lengths[leg % 2] += rng.between(2, 4) as i64;
+        for _ in 0..lengths[leg % 2] {
+            pos = dir.step(pos);
+            if !inbounds(pos) {
+                break 'spiral;
+            }
+        }
+        let obstacle = dir.step(pos);
+        if !inbounds(obstacle) {
+            break;
+        }
+        cells[obstacle.1 as usize][obstacle.0 as usize] = '#';
+        dir = dir.turn_right();
+    }
+
+    let visited = guard_walk(&cells, start).expect("the guard leaves the spiral");
+    for (y, row) in cells.iter_mut().enumerate() {
+        for (x, cell) in row.iter_mut().enumerate() {
+            if *cell == '.' && !visited.contains(&(x as i64, y as i64)) && rng.chance(0.05) {
+                *cell = '#';
+            }
+        }
+    }
+    lines(&cells)
+}
+
+/// The positions the guard visits before leaving the lab, or `None` if the
+/// guard walks in a loop.
+fn guard_walk(cells: &[Vec<char>], start: (i64, i64)) -> Option<HashSet<(i64, i64)>> {
+    let size = cells.len() as i64;
+    let (mut pos, mut dir) = (start, Direction::North);
+    let mut seen = HashSet::new();
+    while seen.insert((pos, dir)) {
+        let next = dir.step(pos);
+        if next.0 < 0 || next.1 < 0 || next.0 >= size || next.1 >= size {
+            return Some(seen.into_iter().map(|(pos, _)| pos).collect());
+        }
+        if cells[next.1 as usize][next.0 as usize] == '#' {
+            dir = dir.turn_right();
+        } else {
+            pos = next;
+        }
+    }
+    None
+}
+
+/// 850 equations of 3 to 12 numbers, each built by combining its numbers with
+/// random operators. Half of the test values are then made unsolvable by an
+/// offset.
+fn day07(rng: &mut Rng) -> String {
+    let mut out = String::new();
+    let mut count = 0;
+    while count < 850 {
+        let numbers: Vec<u64> = (0..rng.between(3, 12))
+            .map(|_| match rng.between(0, 9) {
+                0..=4 => rng.between(1, 9),
+                5..=8 => rng.between(10, 99),
+                _ => rng.between(100, 999),
+            })
+            .collect();
+        let mut value = numbers[0];
+        for &n in &numbers[1..] {
+            value = match rng.between(0, 2) {
+                0 => value + n,
+                1 => value.saturating_mul(n),
+                _ => value
+                    .saturating_mul(10u64.pow(n.ilog10() + 1))
+                    .saturating_add(n),
+            };
+        }
+        // keep the test values in the range of the real input
+        if value >= 1_000_000_000_000_000 {
+            continue;
+        }
+        if rng.chance(0.5) {
+            value += rng.between(1, 1000);
+        }
+        let numbers: Vec<String> = numbers.iter().map(|n| n.to_string()).collect();
+        out += &format!("{}: {}\n", value, numbers.join(" "));
+        count += 1;
+    }
+    out
+}
+
+/// A 50×50 map with 3 or 4 antennas for each of 40 frequencies.
+fn day08(rng: &mut Rng) -> String {
+    let size = 50;
+    let mut cells = vec![vec!['.'; size]; size];
+    let frequencies: Vec<char> = ('0'..='9').chain('a'..='z').chain('A'..='Z').collect();
+    for &frequency in &frequencies[..40] {
+        let mut placed = 0;
+        let wanted = rng.between(3, 4);
+        while placed < wanted {
+            let (x, y) = (
+                rng.between(0, size as u64 - 1) as usize,
+                rng.between(0, size as u64 - 1) as usize,
+            );
+            if cells[y][x] == '.' {
+                cells[y][x] = frequency;
+                placed += 1;
+            }
+        }
+    }
+    lines(&cells)
+}
+
+/// A disk map of 10000 files and 9999 free spans.
+fn day09(rng: &mut Rng) -> String {
+    let mut out = String::new();
+    for i in 0..19999 {
+        let digit = if i % 2 == 0 {
+            rng.between(1, 9)
+        } else {
+            rng.between(0, 9)
+        };
+        out += &digit.to_string();
+    }
+    out.push('\n');
+    out
+}
+
+/// 8 stones with numbers of 1 to 7 digits.
+fn day11(rng: &mut Rng) -> String {
+    let stones: Vec<String> = (0..8)
+        .map(|_| {
+            let digits = rng.between(1, 7) as u32;
+            rng.between(10u64.pow(digits - 1), 10u64.pow(digits) - 1)
+                .to_string()
+        })
+        .collect();
+    stones.join(" ") + "\n"
+}
+
+/// A 140×140 garden split into about 600 regions, each cell taking the plant
+/// of the nearest of 600 random seeds.
+fn day12(rng: &mut Rng) -> String {
+    let size = 140;
+    let seeds: Vec<(i64, i64, char)> = (0..600)
+        .map(|_| {
+            (
+                rng.between(0, size - 1) as i64,
+                rng.between(0, size - 1) as i64,
+                rng.pick(&('A'..='Z').collect::<Vec<char>>()),
+            )
+        })
+        .collect();
+    let cells: Vec<Vec<char>> = (0..size as i64)
+        .map(|y| {
+            (0..size as i64)
+                .map(|x| {
+                    seeds
+                        .iter()
+                        .min_by_key(|(sx, sy, _)| (sx - x).abs() + (sy - y).abs())
+                        .unwrap()
+                        .2
+                })
+                .collect()
+        })
+        .collect();
+    lines(&cells)
+}
+
+/// A 50×50 walled warehouse with about 8% inner walls and 25% boxes, the robot
+/// in the centre, and 20000 random moves on lines of 1000.
+fn day15(rng: &mut Rng) -> String {
+    let size = 50;
+    let mut cells = vec![vec!['#'; size]; size];
+    for row in cells.iter_mut().take(size - 1).skip(1) {
+        for cell in row.iter_mut().take(size - 1).skip(1) {
+            *cell = if rng.chance(0.08) {
+                '#'
+            } else if rng.chance(0.25) {
+                'O'
+            } else {
+                '.'
+            };
+        }
+    }
+    cells[size / 2][size / 2] = '@';
+    let mut out = lines(&cells);
+    out.push('\n');
+    for _ in 0..20 {
+        let moves: String = (0..1000).map(|_| rng.pick(&['^', 'v', '<', '>'])).collect();
+        out += &moves;
+        out.push('\n');
+    }
+    out
+}
+
+/// A 141×141 maze carved by a randomised depth-first search, with 10% of the
+/// remaining inner walls between corridors removed so that there are many
+/// paths. The start is in the bottom left and the end in the top right corner.
+fn day16(rng: &mut Rng) -> String {
+    let size = 141;
+    let mut cells = vec![vec!['#'; size]; size];
+    let mut stack = vec![(1, size - 2)];
+    cells[size - 2][1] = '.';
+    while let Some(&(x, y)) = stack.last() {
+        let mut neighbours: Vec<(usize, usize)> = [(0, -2), (2, 0), (0, 2), (-2, 0)]
+            .iter()
+            .map(|(dx, dy)| ((x as i64 + dx) as usize, (y as i64 + dy) as usize))
+            .filter(|&(nx, ny)| nx > 0 && ny > 0 && nx < size - 1 && ny < size - 1)
+            .filter(|&(nx, ny)| cells[ny][nx] == '#')
+            .collect();
+        if neighbours.is_empty() {
+            stack.pop();
+            continue;
+        }
+        rng.shuffle(&mut neighbours);
+        let (nx, ny) = neighbours[0];
+        cells[(y + ny) / 2][(x + nx) / 2] = '.';
+        cells[ny][nx] = '.';
+        stack.push((nx, ny));
+    }
+    for (y, row) in cells.iter_mut().enumerate().take(size - 1).skip(1) {
+        for (x, cell) in row.iter_mut().enumerate().take(size - 1).skip(1) {
+            // walls between two corridor cells have one odd and one even coordinate
+            if *cell == '#' && (x + y) % 2 == 1 && rng.chance(0.1) {
+                *cell = '.';
+            }
+        }
+    }
+    cells[size - 2][1] = 'S';
+    cells[1][size - 2] = 'E';
+    lines(&cells)
+}
+
+fn lines(cells: &[Vec<char>]) -> String {
+    cells
+        .iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
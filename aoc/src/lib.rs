@@ -0,0 +1,149 @@
+//! Registry of the solved days, used by the `aoc` runner binary.
+
+use aoc_common::{ParseError, Solution};
+
+/// Parses a puzzle input once and solves the requested parts of a day.
+///
+/// The answers are returned in the order of the requested parts, with `None`
+/// for parts that are not solved yet.
+pub type Solver = fn(&str, &[u8]) -> Result<Vec<Option<String>>, ParseError>;
+
+/// A solved day and its solver.
+pub struct Day {
+    pub number: u8,
+    pub solve: Solver,
+}
+
+/// Solves `parts` of `S` for `input`.
+///
+/// Trailing whitespace is removed first because several parsers treat a
+/// final newline as an additional, empty line.
+fn solve<S: Solution>(input: &str, parts: &[u8]) -> Result<Vec<Option<String>>, ParseError> {
+    let input = S::parse(input.trim_end())?;
+    Ok(parts
+        .iter()
+        .map(|part| match part {
+            1 => Some(S::part1(&input).to_string()),
+            2 => S::part2(&input).map(|answer| answer.to_string()),
+            _ => None,
+        })
+        .collect())
+}
+
+/// All solved days in ascending order.
+pub const DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        solve: solve::<day01b::Day01>,
+    },
+    Day {
+        number: 2,
+        solve: solve::<day02b::Day02>,
+    },
+    Day {
+        number: 5,
+        solve: solve::<day05b::Day05>,
+    },
+    Day {
+        number: 6,
+        solve: solve::<day06b::Day06>,
+    },
+    Day {
+        number: 7,
+        solve: solve::<day07b::Day07>,
+    },
+    Day {
+        number: 8,
+        solve: solve::<day08b::Day08>,
+    },
+    Day {
+        number: 9,
+        solve: solve::<day09a::Day09>,
+    },
+    Day {
+        number: 11,
+        solve: solve::<day11b::Day11>,
+    },
+    Day {
+        number: 12,
+        solve: solve::<day12b::Day12>,
+    },
+    Day {
+        number: 15,
+        solve: solve::<day15b::Day15>,
+    },
+    Day {
+        number: 16,
+        solve: solve::<day16a::Day16>,
+    },
+];
+
+/// The days that can also be solved in parallel, with their parallel solvers,
+/// in ascending order.
+pub const PARALLEL_DAYS: &[Day] = &[Day {
+    number: 6,
+    solve: solve::<day06b::Day06Parallel>,
+}];
+
+/// Looks up a day by its number.
+pub fn find_day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+/// Looks up the parallel solver of a day by its number.
+pub fn find_parallel_day(number: u8) -> Option<&'static Day> {
+    PARALLEL_DAYS.iter().find(|day| day.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_sorted() {
+        assert!(DAYS.windows(2).all(|w| w[0].number < w[1].number));
+    }
+
+    #[test]
+    fn test_find_day() {
+        assert_eq!(find_day(6).map(|day| day.number), Some(6));
+        assert!(find_day(3).is_none());
+    }
+
+    #[test]
+    fn test_parallel_days() {
+        for parallel in PARALLEL_DAYS {
+            assert!(find_day(parallel.number).is_some());
+        }
+        assert!(find_parallel_day(6).is_some());
+        assert!(find_parallel_day(1).is_none());
+    }
+
+    #[test]
+    fn test_solve() {
+        let day = find_day(1).unwrap();
+        let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+        let answers = (day.solve)(input, &[1, 2]).unwrap();
+        assert_eq!(
+            answers,
+            vec![Some("11".to_string()), Some("31".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let day = find_day(1).unwrap();
+        let err = (day.solve)("3   4\n4   x\n", &[1]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Day 1, line 2, column 5: expected a number"
+        );
+    }
+
+    #[test]
+    fn test_unsolved_part() {
+        let day = find_day(9).unwrap();
+        let answers = (day.solve)("12345", &[1, 2]).unwrap();
+        assert_eq!(answers, vec![Some("60".to_string()), None]);
+    }
+}
@@ -0,0 +1,372 @@
+use aoc::{find_day, find_parallel_day, Day, DAYS};
+use aoc_common::input::{Source, DEFAULT_PATTERN};
+use aoc_common::{Line, ParseError};
+use clap::{Args, Parser, Subcommand};
+use std::io::{self, BufRead};
+use std::process::ExitCode;
+
+/// Advent of Code 2024 solutions.
+#[derive(Parser)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, or every solved day with `--all`.
+    Run(RunArgs),
+    /// Explain for every report of day 2 whether it is safe, or which level
+    /// has to be removed to make it safe.
+    Safety(InputArgs),
+    /// List the day 5 updates that break ordering rules, with every rule they
+    /// break.
+    Violations(InputArgs),
+    /// Print the day 5 ordering rules as a Graphviz DOT graph.
+    Dot(DotArgs),
+    /// Repair every invalid day 5 update both by sorting it and by moving as
+    /// few pages as possible, and compare the sums of their middle pages.
+    /// Updates whose correct order is not unique are pointed out.
+    Repair(InputArgs),
+    /// Check day 5 updates as they arrive line by line on stdin. `X|Y` lines
+    /// add rules, which apply to every update after them.
+    Stream,
+    /// List the day 5 rules that follow from other rules, the malformed rules
+    /// `X|X`, and the pages of updates that no rule mentions.
+    Redundant(InputArgs),
+}
+
+/// The input of a command that works on the input of one particular day.
+#[derive(Args)]
+struct InputArgs {
+    /// Input file, or `-` to read stdin. Defaults to the file given by `--inputs`.
+    #[arg(long)]
+    input: Option<Source>,
+    /// Location of the default input files. `{day}` is replaced by the
+    /// two-digit day number.
+    #[arg(long, env = "AOC_INPUTS", default_value = DEFAULT_PATTERN)]
+    inputs: String,
+}
+
+impl InputArgs {
+    /// Reads and parses the input of `day`, printing the error if that fails.
+    fn parse<T>(&self, day: u8, parse: fn(&str) -> Result<T, ParseError>) -> Option<(String, T)> {
+        let content = self.read(day)?;
+        match parse(content.trim_end()) {
+            Ok(parsed) => Some((content, parsed)),
+            Err(err) => {
+                eprintln!("{}", err.in_day(day));
+                None
+            }
+        }
+    }
+
+    /// Reads the input of `day`, printing the error if that fails.
+    fn read(&self, day: u8) -> Option<String> {
+        let source = match &self.input {
+            Some(input) => input.clone(),
+            None => Source::for_day(day, &self.inputs),
+        };
+        match source.read() {
+            Ok(content) => Some(content),
+            Err(err) => {
+                eprintln!("Day {}: {}", day, err);
+                None
+            }
+        }
+    }
+}
+
+#[derive(Args)]
+struct DotArgs {
+    #[command(flatten)]
+    input: InputArgs,
+    /// Only print the rules between the pages of the update on this line of
+    /// the input, annotated with its original and corrected order.
+    #[arg(long, value_name = "LINE")]
+    update: Option<usize>,
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to solve.
+    #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+    /// Part to solve. All solved parts are run if omitted.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Input file, or `-` to read stdin. Defaults to the file given by `--inputs`.
+    #[arg(long, conflicts_with = "all")]
+    input: Option<Source>,
+    /// Location of the default input files. `{day}` is replaced by the
+    /// two-digit day number.
+    #[arg(long, env = "AOC_INPUTS", default_value = DEFAULT_PATTERN)]
+    inputs: String,
+    /// Solve every solved day in order.
+    #[arg(long)]
+    all: bool,
+    /// Try the candidate obstacles of day 6, part 2 in parallel on all cores.
+    #[arg(long)]
+    parallel: bool,
+}
+
+/// Solves the requested parts of `day` and prints the answers.
+/// Returns whether every requested part was solved.
+fn run_day(day: &Day, part: Option<u8>, input: &Source) -> bool {
+    let content = match input.read() {
+        Ok(content) => content,
+        Err(err) => {
+            eprintln!("Day {}: {}", day.number, err);
+            return false;
+        }
+    };
+    let parts = part.map_or_else(|| vec![1, 2], |part| vec![part]);
+    let answers = match (day.solve)(&content, &parts) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("{}", err.in_day(day.number));
+            return false;
+        }
+    };
+    let mut ok = true;
+    for (part, answer) in parts.iter().zip(answers) {
+        match answer {
+            Some(answer) => println!("Day {}, part {}: {}", day.number, part, answer),
+            // unsolved parts are only an error when they were asked for explicitly
+            None if parts.len() > 1 => {}
+            None => {
+                eprintln!("Day {}, part {}: not solved yet", day.number, part);
+                ok = false;
+            }
+        }
+    }
+    ok
+}
+
+fn run(args: &RunArgs) -> ExitCode {
+    let days: Vec<&Day> = match args.day {
+        Some(number) => match find_day(number) {
+            Some(day) => vec![day],
+            None => {
+                eprintln!("Day {} is not solved yet", number);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => DAYS.iter().collect(),
+    };
+    let mut ok = true;
+    let mut missing = false;
+    for mut day in days {
+        if args.parallel {
+            day = find_parallel_day(day.number).unwrap_or(day);
+        }
+        let input = match &args.input {
+            Some(input) => input.clone(),
+            None => Source::for_day(day.number, &args.inputs),
+        };
+        missing |= matches!(&input, Source::File(path) if !path.exists());
+        ok &= run_day(day, args.part, &input);
+    }
+    if missing {
+        eprintln!(
+            "Pass --input PATH (or - for stdin), or point --inputs / AOC_INPUTS to the input files"
+        );
+    }
+    if ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Prints the safety verdict of every report of day 2.
+fn safety(args: &InputArgs) -> ExitCode {
+    let Some((content, reports)) = args.parse(2, day02b::parse_input) else {
+        return ExitCode::FAILURE;
+    };
+    for (line, report) in content.lines().zip(&reports) {
+        println!("{}: {}", line.trim(), day02b::verdict(report));
+    }
+    ExitCode::SUCCESS
+}
+
+/// Prints every day 5 update that breaks rules, with the rules it breaks.
+fn violations(args: &InputArgs) -> ExitCode {
+    let Some((_, (rules, updates))) = args.parse(5, day05b::parse_input) else {
+        return ExitCode::FAILURE;
+    };
+    let deps = day05b::dependents(&rules);
+    let mut invalid = 0;
+    for (i, update) in updates.iter().enumerate() {
+        let violations = day05b::violations(update, &deps);
+        if violations.is_empty() {
+            continue;
+        }
+        invalid += 1;
+        let pages: Vec<String> = update.iter().map(|page| page.to_string()).collect();
+        println!(
+            "line {}: {}",
+            day05b::update_line(&rules, i),
+            pages.join(",")
+        );
+        for violation in violations {
+            println!("  {}", violation);
+        }
+    }
+    println!("{} of {} updates break rules", invalid, updates.len());
+    ExitCode::SUCCESS
+}
+
+/// Prints the day 5 rule graph, or the subgraph of one update, as DOT.
+fn dot(args: &DotArgs) -> ExitCode {
+    let Some((_, (rules, updates))) = args.input.parse(5, day05b::parse_input) else {
+        return ExitCode::FAILURE;
+    };
+    let update = match args.update {
+        Some(line) => {
+            let update = (0..updates.len())
+                .find(|&i| day05b::update_line(&rules, i) == line)
+                .map(|i| updates[i].as_slice());
+            if update.is_none() {
+                eprintln!("Day 5: line {} is not an update", line);
+                return ExitCode::FAILURE;
+            }
+            update
+        }
+        None => None,
+    };
+    print!("{}", day05b::to_dot(&day05b::graph(&rules), update));
+    ExitCode::SUCCESS
+}
+
+/// Prints both repairs of every invalid day 5 update, whether the sorted
+/// order is the only correct one, and the middle sums of both repairs. Rules
+/// with a cycle between the pages of an update are reported as a parse error.
+fn repair(args: &InputArgs) -> ExitCode {
+    use aoc_common::Solution;
+    use day05b::repair::{self, Strategy};
+
+    let Some((_, (rules, updates))) = args.parse(5, day05b::Day05::parse) else {
+        return ExitCode::FAILURE;
+    };
+    let g = day05b::graph(&rules);
+    let join = |pages: &[i64]| {
+        let pages: Vec<String> = pages.iter().map(|page| page.to_string()).collect();
+        pages.join(",")
+    };
+    for (i, update) in updates.iter().enumerate() {
+        if day05b::update_obeys_deps(update, &g) {
+            continue;
+        }
+        let sorted = day05b::kahn_order(&g, update).expect("checked by parse");
+        let minimal = repair::minimal_moves(&g, update).expect("checked by parse");
+        println!("line {}: {}", day05b::update_line(&rules, i), join(update));
+        println!("  sorted:        {}", join(&sorted.order));
+        if let Some([a, b]) = sorted.ambiguous {
+            println!("  order not unique: {} and {} may swap", a, b);
+        }
+        println!("  minimal moves: {}", join(&minimal.order));
+        for m in &minimal.moves {
+            println!("    {}", m);
+        }
+    }
+    for (name, strategy) in [
+        ("sorted", Strategy::Sort),
+        ("minimal moves", Strategy::MinimalMoves),
+    ] {
+        let sum = repair::middle_sum(&g, &updates, strategy).expect("checked by parse");
+        println!("middle pages, {}: {}", name, sum);
+    }
+    ExitCode::SUCCESS
+}
+
+/// Checks every update on stdin against the rules read so far and prints the
+/// rules it breaks. Malformed lines are reported and skipped.
+fn stream() -> ExitCode {
+    let mut rules = day05b::rules::OrderingRules::new();
+    let mut status = ExitCode::SUCCESS;
+    for (i, text) in io::stdin().lock().lines().enumerate() {
+        let text = match text {
+            Ok(text) => text,
+            Err(err) => {
+                eprintln!("Day 5: {}", err);
+                return ExitCode::FAILURE;
+            }
+        };
+        let line = Line {
+            day: 5,
+            number: i + 1,
+            text: text.trim_end(),
+        };
+        match rules.feed(&line) {
+            Ok(None) => {}
+            Ok(Some(violations)) if violations.is_empty() => println!("line {}: ok", line.number),
+            Ok(Some(violations)) => {
+                println!("line {}: {}", line.number, line.text);
+                for violation in violations {
+                    println!("  {}", violation);
+                }
+            }
+            Err(err) => {
+                eprintln!("{}", err);
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+    status
+}
+
+/// Prints the redundant day 5 rules, the malformed rules `X|X` and the pages
+/// without rules.
+fn redundant(args: &InputArgs) -> ExitCode {
+    use day05b::reduction;
+
+    let Some((_, (rules, updates))) = args.parse(5, day05b::parse_input) else {
+        return ExitCode::FAILURE;
+    };
+    let redundant = reduction::redundant_rules(&rules);
+    println!("{} of {} rules are redundant", redundant.len(), rules.len());
+    for rule in &redundant {
+        println!("  {}", rule);
+    }
+    for (x, _) in reduction::self_loops(&rules) {
+        println!(
+            "malformed rule {}|{}: a page cannot come before itself",
+            x, x
+        );
+    }
+    let reduced = reduction::transitive_reduction(&rules);
+    let changed = reduction::changed_updates(&rules, &reduced, &updates);
+    if !changed.is_empty() {
+        println!("dropping them changes whether these updates obey the rules:");
+        for i in changed {
+            let pages: Vec<String> = updates[i].iter().map(|page| page.to_string()).collect();
+            println!(
+                "  line {}: {}",
+                day05b::update_line(&rules, i),
+                pages.join(",")
+            );
+        }
+    }
+    let pages: Vec<String> = reduction::pages_without_rules(&rules, &updates)
+        .iter()
+        .map(|page| page.to_string())
+        .collect();
+    if !pages.is_empty() {
+        println!("pages without rules: {}", pages.join(","));
+    }
+    ExitCode::SUCCESS
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match &cli.command {
+        Command::Run(args) => run(args),
+        Command::Safety(args) => safety(args),
+        Command::Violations(args) => violations(args),
+        Command::Dot(args) => dot(args),
+        Command::Repair(args) => repair(args),
+        Command::Stream => stream(),
+        Command::Redundant(args) => redundant(args),
+    }
+}
@@ -0,0 +1,60 @@
+//! Runs subcommands of the `aoc` binary on small inputs given on stdin.
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+/// Runs `aoc` with `args` and `input` on stdin.
+fn aoc(args: &[&str], input: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn test_repair_with_cycle() {
+    let output = aoc(&["repair", "--input", "-"], "1|2\n2|1\n\n1,2\n");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.starts_with("Day 5, line 4"), "{}", stderr);
+    assert!(stderr.contains("contain the cycle"), "{}", stderr);
+}
+
+#[test]
+fn test_redundant_with_self_loop() {
+    let output = aoc(
+        &["redundant", "--input", "-"],
+        "1|1\n1|2\n2|3\n1|3\n\n1,2,3\n",
+    );
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.starts_with("1 of 4 rules are redundant\n"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("malformed rule 1|1"), "{}", stdout);
+}
+
+#[test]
+fn test_repair() {
+    let output = aoc(&["repair", "--input", "-"], "1|2\n2|3\n\n3,2,1\n");
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("  sorted:        1,2,3\n"), "{}", stdout);
+    assert!(
+        stdout.contains("middle pages, minimal moves: 2\n"),
+        "{}",
+        stdout
+    );
+}
@@ -0,0 +1,91 @@
+//! Runs every example in `dayNNa/examples/` through the solvers of its day and
+//! compares the answers with the expected ones.
+//!
+//! An example `NAME.txt` has its expected answers in `NAME.answers`, one
+//! `partN: ANSWER` line per part. Parts without an expected answer are not
+//! checked, nor are parts that are not solved yet.
+
+use aoc::{DAYS, PARALLEL_DAYS};
+use aoc_common::ParseError;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn examples_dir(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .join(format!("day{:02}a", day))
+        .join("examples")
+}
+
+/// The examples of `day` as `(name, input, expected answers of part 1 and 2)`.
+fn examples(day: u8) -> Vec<(String, String, [Option<String>; 2])> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(examples_dir(day))
+        .unwrap_or_else(|err| panic!("day {} has no examples: {}", day, err))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .collect();
+    paths.sort();
+    paths
+        .into_iter()
+        .map(|path| {
+            let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+            let input = fs::read_to_string(&path).unwrap();
+            let answers = fs::read_to_string(path.with_extension("answers"))
+                .unwrap_or_else(|err| panic!("day {} example {}: {}", day, name, err));
+            let mut expected = [None, None];
+            for line in answers.lines() {
+                match line.split_once(": ") {
+                    Some(("part1", answer)) => expected[0] = Some(answer.to_string()),
+                    Some(("part2", answer)) => expected[1] = Some(answer.to_string()),
+                    _ => panic!("day {} example {}: bad answer line {:?}", day, name, line),
+                }
+            }
+            (name, input, expected)
+        })
+        .collect()
+}
+
+#[test]
+fn test_examples() {
+    let mut failures = Vec::new();
+    // the parallel solvers have to give the same answers
+    for day in DAYS.iter().chain(PARALLEL_DAYS) {
+        for (name, input, expected) in examples(day.number) {
+            let answers = match (day.solve)(&input, &[1, 2]) {
+                Ok(answers) => answers,
+                Err(err) => {
+                    let err = ParseError { day: None, ..err };
+                    failures.push(format!("Day {}, example {}: {}", day.number, name, err));
+                    continue;
+                }
+            };
+            for (part, (answer, expected)) in answers.into_iter().zip(expected).enumerate() {
+                if let (Some(answer), Some(expected)) = (answer, expected) {
+                    if answer != expected {
+                        failures.push(format!(
+                            "Day {}, part {}, example {}: expected {}, got {}",
+                            day.number,
+                            part + 1,
+                            name,
+                            expected,
+                            answer
+                        ));
+                    }
+                }
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn test_every_day_has_an_example() {
+    for day in DAYS {
+        assert!(
+            examples_dir(day.number).join("example.txt").exists(),
+            "day {} has no examples/example.txt",
+            day.number
+        );
+    }
+}
@@ -0,0 +1,70 @@
+use aoc_common::{Line, ParseError};
+
+const DAY: u8 = 1;
+
+/// Computes the total distance between two lists by pairing up the smallest
+/// numbers in each list, then the second-smallest, and so on, and summing the
+/// absolute differences of each pair.
+pub fn total_distance(l: &mut [i64], r: &mut [i64]) -> i64 {
+    l.sort_unstable();
+    r.sort_unstable();
+    l.iter().zip(r.iter()).map(|(a, b)| (a - b).abs()).sum()
+}
+
+/// Parses the two columns of numbers into a left and a right list.
+///
+/// The columns may be separated by any whitespace and the numbers may have
+/// any width. CRLF line endings and blank lines at the end of the input are
+/// accepted; a line with more or fewer than two numbers is an error.
+pub fn parse_input(input: &str) -> Result<(Vec<i64>, Vec<i64>), ParseError> {
+    let (mut a, mut b) = (Vec::with_capacity(1000), Vec::with_capacity(1000));
+    for line in Line::all(DAY, input.trim_end()) {
+        let mut fields = line.text.split_whitespace();
+        let (left, right) = match (fields.next(), fields.next(), fields.next()) {
+            (Some(left), Some(right), None) => (left, right),
+            (_, _, Some(extra)) => return Err(line.error_at(extra, "only two columns")),
+            _ => return Err(line.error_at_end("two columns separated by whitespace")),
+        };
+        a.push(line.parse(left, "a number")?);
+        b.push(line.parse(right, "a number")?);
+    }
+    Ok((a, b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let (l, r) = parse_input("3   4\n4   3\n2   5").unwrap();
+        assert_eq!(l, vec![3, 4, 2]);
+        assert_eq!(r, vec![4, 3, 5]);
+    }
+
+    #[test]
+    fn test_parse_input_errors() {
+        let err = parse_input("3   4\n4").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(2, 2, "two columns separated by whitespace").in_day(1)
+        );
+        let err = parse_input("3   4\n4   x").unwrap_err();
+        assert_eq!(err, ParseError::new(2, 5, "a number").in_day(1));
+        let err = parse_input("3   4\n4 5 6\n").unwrap_err();
+        assert_eq!(err, ParseError::new(2, 5, "only two columns").in_day(1));
+        let err = parse_input("3   4\n\n4   5").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(2, 1, "two columns separated by whitespace").in_day(1)
+        );
+    }
+
+    #[test]
+    fn test_parse_input_whitespace() {
+        let (l, r) = parse_input("3   4\r\n12\t5\r\n  7 100  \r\n\r\n\n").unwrap();
+        assert_eq!(l, vec![3, 12, 7]);
+        assert_eq!(r, vec![4, 5, 100]);
+        assert_eq!(parse_input("").unwrap(), (vec![], vec![]));
+    }
+}
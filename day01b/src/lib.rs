@@ -0,0 +1,193 @@
+use aoc_common::{Line, ParseError, Solution};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+pub mod linear;
+
+pub use linear::{similarity_with, total_distance_with, Strategy};
+
+const DAY: u8 = 1;
+
+/// Calculate a total similarity score by adding up each number in the left list
+/// after multiplying it by the number of times that number appears in the right list.
+pub fn similarity(l: &[i64], r: &[i64]) -> i64 {
+    let mut r_counts = HashMap::new();
+    for i in r.iter() {
+        *r_counts.entry(i).or_insert(0) += 1;
+    }
+    l.iter().map(|i| i * r_counts.get(i).unwrap_or(&0)).sum()
+}
+
+/// Parses any number of whitespace-separated columns of numbers. The first
+/// line sets the number of columns; every other line must have as many.
+/// Like `day01a::parse_input`, CRLF and blank lines at the end are accepted.
+pub fn parse_columns(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    let mut columns: Vec<Vec<i64>> = Vec::new();
+    for line in Line::all(DAY, input.trim_end()) {
+        let fields: Vec<&str> = line.text.split_whitespace().collect();
+        if line.number == 1 {
+            columns.resize(fields.len(), Vec::new());
+        }
+        if fields.is_empty() {
+            return Err(line.error_at_end("columns of numbers separated by whitespace"));
+        }
+        let expected = || format!("{} columns separated by whitespace", columns.len());
+        if fields.len() > columns.len() {
+            return Err(line.error_at(fields[columns.len()], expected()));
+        }
+        if fields.len() < columns.len() {
+            return Err(line.error_at_end(expected()));
+        }
+        for (column, field) in columns.iter_mut().zip(fields) {
+            column.push(line.parse(field, "a number")?);
+        }
+    }
+    Ok(columns)
+}
+
+/// A way to compare two columns of numbers.
+pub trait Metric {
+    /// Compares `left` with `right`. Both are sorted in ascending order and
+    /// have the same length.
+    fn compare(&self, left: &[i64], right: &[i64]) -> i64;
+}
+
+/// Pairs up the numbers of both columns by rank and sums `distance` over the
+/// pairs, e.g. `RankDistance(|a: i64, b: i64| (a - b).pow(4))`.
+pub struct RankDistance<F>(pub F);
+
+impl<F: Fn(i64, i64) -> i64> Metric for RankDistance<F> {
+    fn compare(&self, left: &[i64], right: &[i64]) -> i64 {
+        left.iter().zip(right).map(|(&a, &b)| (self.0)(a, b)).sum()
+    }
+}
+
+/// The total distance of part 1: the sum of the absolute differences of the
+/// numbers paired up by rank.
+pub struct AbsoluteDifference;
+
+impl Metric for AbsoluteDifference {
+    fn compare(&self, left: &[i64], right: &[i64]) -> i64 {
+        RankDistance(|a: i64, b: i64| (a - b).abs()).compare(left, right)
+    }
+}
+
+/// The sum of the squared differences of the numbers paired up by rank.
+pub struct SquaredDifference;
+
+impl Metric for SquaredDifference {
+    fn compare(&self, left: &[i64], right: &[i64]) -> i64 {
+        RankDistance(|a: i64, b: i64| (a - b).pow(2)).compare(left, right)
+    }
+}
+
+/// The similarity score of part 2. Unlike the distances it is not symmetric:
+/// it weighs the numbers of the left column by their count in the right one.
+pub struct Similarity;
+
+impl Metric for Similarity {
+    fn compare(&self, left: &[i64], right: &[i64]) -> i64 {
+        similarity(left, right)
+    }
+}
+
+/// Compares every pair of columns with `metric`. Returns `(i, j, score)` for
+/// every `i < j`, with column `i` as the left and column `j` as the right
+/// column. Each column is sorted once, not once per pair.
+pub fn compare_columns(columns: &[Vec<i64>], metric: &impl Metric) -> Vec<(usize, usize, i64)> {
+    let sorted: Vec<Vec<i64>> = columns
+        .iter()
+        .map(|column| {
+            let mut column = column.clone();
+            column.sort_unstable();
+            column
+        })
+        .collect();
+    let mut scores = Vec::new();
+    for i in 0..sorted.len() {
+        for j in i + 1..sorted.len() {
+            scores.push((i, j, metric.compare(&sorted[i], &sorted[j])));
+        }
+    }
+    scores
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = (Vec<i64>, Vec<i64>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        day01a::parse_input(input)
+    }
+
+    fn part1((l, r): &Self::Input) -> impl Display {
+        total_distance_with(&mut l.clone(), &mut r.clone(), Strategy::Auto)
+    }
+
+    fn part2((l, r): &Self::Input) -> Option<impl Display> {
+        Some(similarity_with(l, r, Strategy::Auto))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+    #[test]
+    fn test_parse_columns() {
+        let columns = parse_columns("1 2 3\r\n4\t5  6\r\n\r\n").unwrap();
+        assert_eq!(columns, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+        let err = parse_columns("1 2 3\n4 5").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(2, 4, "3 columns separated by whitespace").in_day(1)
+        );
+        let err = parse_columns("1 2\n4 5 6").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(2, 5, "2 columns separated by whitespace").in_day(1)
+        );
+        assert_eq!(parse_columns("").unwrap(), Vec::<Vec<i64>>::new());
+    }
+
+    #[test]
+    fn test_two_columns_match_parts() {
+        let columns = parse_columns(EXAMPLE).unwrap();
+        let (l, r) = day01a::parse_input(EXAMPLE).unwrap();
+        let distance = day01a::total_distance(&mut l.clone(), &mut r.clone());
+        assert_eq!(
+            compare_columns(&columns, &AbsoluteDifference),
+            vec![(0, 1, distance)]
+        );
+        assert_eq!(
+            compare_columns(&columns, &Similarity),
+            vec![(0, 1, similarity(&l, &r))]
+        );
+        assert_eq!(compare_columns(&columns, &Similarity), vec![(0, 1, 31)]);
+    }
+
+    #[test]
+    fn test_compare_columns() {
+        let columns = parse_columns("1 4 1\n3 2 3\n5 6 5").unwrap();
+        assert_eq!(
+            compare_columns(&columns, &AbsoluteDifference),
+            vec![(0, 1, 3), (0, 2, 0), (1, 2, 3)]
+        );
+        assert_eq!(
+            compare_columns(&columns, &SquaredDifference),
+            vec![(0, 1, 3), (0, 2, 0), (1, 2, 3)]
+        );
+        let cubed = RankDistance(|a: i64, b: i64| (a - b).pow(3));
+        assert_eq!(
+            compare_columns(&columns, &cubed),
+            vec![(0, 1, -3), (0, 2, 0), (1, 2, 3)]
+        );
+        assert_eq!(
+            compare_columns(&columns, &Similarity),
+            vec![(0, 1, 0), (0, 2, 9), (1, 2, 0)]
+        );
+    }
+}
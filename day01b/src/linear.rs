@@ -0,0 +1,232 @@
+//! Linear-time alternatives to sorting and hashing for columns whose values
+//! lie in a bounded range: counting sort and a flat count array when the
+//! range is small compared to the column, LSD radix sort otherwise.
+
+use crate::similarity;
+
+/// How `total_distance_with` and `similarity_with` sort and count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Strategy {
+    /// `sort_unstable` and a `HashMap` of counts, as in `total_distance` and
+    /// `similarity`.
+    Comparison,
+    /// Counting or radix sort, and a flat count array or a merge of the
+    /// sorted columns.
+    Linear,
+    /// `Linear` for long columns whose values span less than `MAX_COUNTS`,
+    /// where it is faster, `Comparison` otherwise. Wider spans need the
+    /// slower radix sort and merge instead of a flat count array.
+    #[default]
+    Auto,
+}
+
+/// Columns at least this long use the linear strategy with `Strategy::Auto`.
+const AUTO_MIN_LEN: usize = 10_000;
+
+/// The largest value range counted in a flat array, 16 MiB of `u32` counts.
+const MAX_COUNTS: usize = 1 << 22;
+
+impl Strategy {
+    /// Whether to use the linear strategy for a column of `len` values that
+    /// span `span`.
+    fn is_linear(self, len: usize, span: u64) -> bool {
+        match self {
+            Strategy::Comparison => false,
+            Strategy::Linear => true,
+            Strategy::Auto => len >= AUTO_MIN_LEN && span < MAX_COUNTS as u64,
+        }
+    }
+}
+
+/// The smallest value and the difference between the largest and the
+/// smallest value, or `None` for an empty column.
+fn span(column: &[i64]) -> Option<(i64, u64)> {
+    let min = *column.iter().min()?;
+    let max = *column.iter().max()?;
+    Some((min, max.abs_diff(min)))
+}
+
+/// Sorts `column` in ascending order with `strategy`.
+pub fn sort(column: &mut [i64], strategy: Strategy) {
+    let Some((min, span)) = span(column) else {
+        return;
+    };
+    if !strategy.is_linear(column.len(), span) {
+        column.sort_unstable();
+        return;
+    }
+    // counting sort pays off while the count array is not much larger than
+    // the column itself
+    if span < (2 * column.len() as u64).min(MAX_COUNTS as u64) {
+        counting_sort(column, min, span as usize + 1);
+    } else {
+        radix_sort(column, min, span);
+    }
+}
+
+fn counting_sort(column: &mut [i64], min: i64, range: usize) {
+    let mut counts = vec![0u32; range];
+    for &value in column.iter() {
+        counts[value.abs_diff(min) as usize] += 1;
+    }
+    let mut i = 0;
+    for (offset, &count) in counts.iter().enumerate() {
+        column[i..i + count as usize].fill(min + offset as i64);
+        i += count as usize;
+    }
+}
+
+/// LSD radix sort on the offsets from `min`, one byte per pass and only as
+/// many passes as the largest offset `span` needs.
+fn radix_sort(column: &mut [i64], min: i64, span: u64) {
+    let mut keys: Vec<u64> = column.iter().map(|&value| value.abs_diff(min)).collect();
+    let mut buffer = vec![0u64; keys.len()];
+    let passes = (u64::BITS - span.leading_zeros()).div_ceil(8);
+    for pass in 0..passes {
+        let shift = 8 * pass;
+        let mut offsets = [0usize; 257];
+        for &key in &keys {
+            offsets[((key >> shift) & 0xff) as usize + 1] += 1;
+        }
+        for digit in 0..256 {
+            offsets[digit + 1] += offsets[digit];
+        }
+        for &key in &keys {
+            let digit = ((key >> shift) & 0xff) as usize;
+            buffer[offsets[digit]] = key;
+            offsets[digit] += 1;
+        }
+        std::mem::swap(&mut keys, &mut buffer);
+    }
+    for (value, key) in column.iter_mut().zip(keys) {
+        *value = min.wrapping_add_unsigned(key);
+    }
+}
+
+/// `day01a::total_distance` with the sorting done by `strategy`.
+pub fn total_distance_with(l: &mut [i64], r: &mut [i64], strategy: Strategy) -> i64 {
+    sort(l, strategy);
+    sort(r, strategy);
+    l.iter().zip(r.iter()).map(|(a, b)| (a - b).abs()).sum()
+}
+
+/// `similarity` with the counting done by `strategy`.
+pub fn similarity_with(l: &[i64], r: &[i64], strategy: Strategy) -> i64 {
+    let Some((min, span)) = span(r) else {
+        return 0;
+    };
+    if !strategy.is_linear(l.len().max(r.len()), span) {
+        return similarity(l, r);
+    }
+    if span < MAX_COUNTS as u64 {
+        let mut counts = vec![0u32; span as usize + 1];
+        for &value in r {
+            counts[value.abs_diff(min) as usize] += 1;
+        }
+        l.iter()
+            .filter(|&&value| value >= min && value.abs_diff(min) <= span)
+            .map(|&value| value * counts[value.abs_diff(min) as usize] as i64)
+            .sum()
+    } else {
+        merged_similarity(l, r, strategy)
+    }
+}
+
+/// Counts the values of `l` in `r` by walking both columns in sorted order.
+fn merged_similarity(l: &[i64], r: &[i64], strategy: Strategy) -> i64 {
+    let (mut l, mut r) = (l.to_vec(), r.to_vec());
+    sort(&mut l, strategy);
+    sort(&mut r, strategy);
+    let mut total = 0;
+    let mut j = 0;
+    let mut count = 0;
+    for (i, &value) in l.iter().enumerate() {
+        // a repeated value has the same count as before
+        if i == 0 || l[i - 1] != value {
+            while j < r.len() && r[j] < value {
+                j += 1;
+            }
+            let start = j;
+            while j < r.len() && r[j] == value {
+                j += 1;
+            }
+            count = (j - start) as i64;
+        }
+        total += value * count;
+    }
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deterministic pseudo-random columns, `len` values in `min..min + range`.
+    fn column(seed: u64, len: usize, min: i64, range: u64) -> Vec<i64> {
+        let mut state = seed;
+        (0..len)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                min + ((state >> 33) % range) as i64
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_sort() {
+        for (len, min, range) in [
+            (0, 0, 1),
+            (1, 5, 1),
+            (1000, 10000, 90000),
+            (1000, -50, 100),
+            (20000, 0, 10),
+            (5000, -(1 << 40), 1 << 41),
+        ] {
+            let values = column(len as u64, len, min, range);
+            let mut expected = values.clone();
+            expected.sort_unstable();
+            for strategy in [Strategy::Comparison, Strategy::Linear, Strategy::Auto] {
+                let mut sorted = values.clone();
+                sort(&mut sorted, strategy);
+                assert_eq!(sorted, expected, "{:?} {} {} {}", strategy, len, min, range);
+            }
+        }
+        let mut extremes = vec![i64::MAX, 0, i64::MIN, -1, 1, i64::MAX];
+        sort(&mut extremes, Strategy::Linear);
+        assert_eq!(extremes, vec![i64::MIN, -1, 0, 1, i64::MAX, i64::MAX]);
+    }
+
+    #[test]
+    fn test_auto_falls_back_on_wide_spans() {
+        assert!(Strategy::Auto.is_linear(AUTO_MIN_LEN, 1000));
+        assert!(!Strategy::Auto.is_linear(AUTO_MIN_LEN - 1, 1000));
+        assert!(!Strategy::Auto.is_linear(AUTO_MIN_LEN, MAX_COUNTS as u64));
+        assert!(Strategy::Linear.is_linear(1, MAX_COUNTS as u64));
+    }
+
+    #[test]
+    fn test_identical_answers() {
+        for (len, min, range) in [
+            (0, 0, 1),
+            (1000, 10000, 90000),
+            (1000, 10000, 100),
+            (20000, -100, 300),
+            (5000, -(1 << 40), 1 << 41),
+        ] {
+            let l = column(1, len, min, range);
+            // half of the right column is taken from the left one
+            let mut r = column(2, len, min, range);
+            r[..len / 2].copy_from_slice(&l[len / 2..len / 2 * 2]);
+            let distance = day01a::total_distance(&mut l.clone(), &mut r.clone());
+            let score = similarity(&l, &r);
+            for strategy in [Strategy::Comparison, Strategy::Linear, Strategy::Auto] {
+                let actual = total_distance_with(&mut l.clone(), &mut r.clone(), strategy);
+                assert_eq!(actual, distance, "{:?} {} {}", strategy, len, range);
+                assert_eq!(similarity_with(&l, &r, strategy), score, "{:?}", strategy);
+            }
+            assert_eq!(merged_similarity(&l, &r, Strategy::Linear), score);
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use aoc_common::{Line, ParseError};
+
+const DAY: u8 = 2;
+
+/// Parses one report of whitespace separated levels per line.
+pub fn parse_input(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    Line::all(DAY, input)
+        .map(|line| {
+            line.text
+                .split_whitespace()
+                .map(|level| line.parse(level, "a number"))
+                .collect()
+        })
+        .collect()
+}
+
+/// The directions in which the levels of a safe report may change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Trend {
+    /// All increasing or all decreasing, whichever the report starts with.
+    #[default]
+    Either,
+    /// All increasing.
+    Increasing,
+    /// All decreasing.
+    Decreasing,
+    /// Every step may go up or down.
+    Any,
+}
+
+/// The rules a report has to follow to be safe. The default policy is the
+/// puzzle's: strictly increasing or strictly decreasing in steps of 1 to 3.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SafetyPolicy {
+    /// The smallest allowed difference between neighbouring levels.
+    pub min_step: i64,
+    /// The largest allowed difference between neighbouring levels.
+    pub max_step: i64,
+    /// Whether neighbouring levels may be equal, regardless of `min_step`.
+    pub allow_equal: bool,
+    pub trend: Trend,
+}
+
+impl Default for SafetyPolicy {
+    fn default() -> Self {
+        SafetyPolicy {
+            min_step: 1,
+            max_step: 3,
+            allow_equal: false,
+            trend: Trend::Either,
+        }
+    }
+}
+
+impl SafetyPolicy {
+    /// The signs the steps of `report` may have: `Some(1)` for increasing,
+    /// `Some(-1)` for decreasing, `None` for either. `Trend::Either` fixes the
+    /// sign of the first step that is not zero.
+    pub fn sign(&self, report: &[i64]) -> Option<i64> {
+        match self.trend {
+            Trend::Increasing => Some(1),
+            Trend::Decreasing => Some(-1),
+            Trend::Any => None,
+            Trend::Either => report
+                .windows(2)
+                .map(|w| (w[1] - w[0]).signum())
+                .find(|&sign| sign != 0)
+                .or(Some(1)),
+        }
+    }
+
+    /// Whether a step from level `a` to level `b` is allowed when the steps
+    /// must have `sign`.
+    pub fn allows_step(&self, a: i64, b: i64, sign: Option<i64>) -> bool {
+        let d = b - a;
+        if d == 0 {
+            return self.allow_equal;
+        }
+        sign.is_none_or(|sign| d.signum() == sign)
+            && (self.min_step..=self.max_step).contains(&d.abs())
+    }
+}
+
+/// Whether all steps of `report` follow `policy`.
+pub fn is_safe_with(report: &[i64], policy: &SafetyPolicy) -> bool {
+    let sign = policy.sign(report);
+    report
+        .windows(2)
+        .all(|w| policy.allows_step(w[0], w[1], sign))
+}
+
+/// Whether `n` is safe under the default policy.
+pub fn is_safe(n: &[i64]) -> bool {
+    is_safe_with(n, &SafetyPolicy::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(
+            parse_input("7 6 4\n1 2").unwrap(),
+            vec![vec![7, 6, 4], vec![1, 2]]
+        );
+        let err = parse_input("7 6 4\n1 2a").unwrap_err();
+        assert_eq!(err, ParseError::new(2, 3, "a number").in_day(2));
+    }
+
+    #[test]
+    fn test_is_safe_with() {
+        let wide = SafetyPolicy {
+            max_step: 5,
+            ..Default::default()
+        };
+        assert!(!is_safe(&[1, 2, 7, 8, 9]));
+        assert!(is_safe_with(&[1, 2, 7, 8, 9], &wide));
+        assert!(!is_safe_with(&[1, 2, 8, 9], &wide));
+
+        let flat = SafetyPolicy {
+            allow_equal: true,
+            ..Default::default()
+        };
+        assert!(!is_safe(&[8, 6, 4, 4, 1]));
+        assert!(is_safe_with(&[8, 6, 4, 4, 1], &flat));
+        assert!(is_safe_with(&[4, 4, 6, 7], &flat));
+        assert!(!is_safe_with(&[4, 4, 6, 5], &flat));
+
+        let increasing = SafetyPolicy {
+            trend: Trend::Increasing,
+            ..Default::default()
+        };
+        assert!(is_safe_with(&[1, 3, 6], &increasing));
+        assert!(!is_safe_with(&[6, 3, 1], &increasing));
+        assert!(is_safe_with(&[6, 3, 1], &SafetyPolicy::default()));
+
+        let zigzag = SafetyPolicy {
+            trend: Trend::Any,
+            ..Default::default()
+        };
+        assert!(is_safe_with(&[1, 3, 2, 4, 5], &zigzag));
+        assert!(!is_safe_with(&[1, 3, 3, 4], &zigzag));
+    }
+}
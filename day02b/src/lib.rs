@@ -0,0 +1,324 @@
+use aoc_common::{ParseError, Solution};
+use std::fmt::{self, Display};
+
+pub use day02a::{is_safe, is_safe_with, parse_input, SafetyPolicy, Trend};
+
+/// The signs to try for the steps of `report`: the sign of a safe report
+/// decides which steps are allowed, and with `Trend::Either` it depends on
+/// which levels are removed, so both are tried.
+fn signs(report: &[i64], policy: &SafetyPolicy) -> Vec<Option<i64>> {
+    match policy.trend {
+        Trend::Either => vec![Some(1), Some(-1)],
+        _ => vec![policy.sign(report)],
+    }
+}
+
+/// The index of the first level whose step to the next one is not allowed.
+fn first_violation(report: &[i64], policy: &SafetyPolicy, sign: Option<i64>) -> Option<usize> {
+    report
+        .windows(2)
+        .position(|w| !policy.allows_step(w[0], w[1], sign))
+}
+
+/// `report` without the level at index `i`.
+fn without(report: &[i64], i: usize) -> Vec<i64> {
+    report[..i]
+        .iter()
+        .chain(&report[i + 1..])
+        .copied()
+        .collect()
+}
+
+/// Whether a report is safe, and if not, why.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SafetyVerdict {
+    /// Safe as it is.
+    Safe,
+    /// Safe after removing the level `value` at `index`.
+    SafeWithout { index: usize, value: i64 },
+    /// Not safe even after removing one level. The step between the levels
+    /// at `index` and `index + 1`, `window`, is the first that breaks the
+    /// policy.
+    Unsafe { index: usize, window: [i64; 2] },
+}
+
+impl Display for SafetyVerdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SafetyVerdict::Safe => write!(f, "safe"),
+            SafetyVerdict::SafeWithout { index, value } => {
+                write!(f, "safe without level {} at index {}", value, index)
+            }
+            SafetyVerdict::Unsafe { index, window } => write!(
+                f,
+                "unsafe, {} {} at index {} is the first bad step",
+                window[0], window[1], index
+            ),
+        }
+    }
+}
+
+/// Judges `report` under `policy`, allowing one level to be removed. Of the
+/// levels whose removal makes the report safe, the one with the lowest index
+/// is reported.
+///
+/// Whether a step is allowed only depends on its two levels and the sign, so
+/// a safe report keeps no violating pair of neighbours: of the first such
+/// pair, one level has to be removed.
+pub fn verdict_with(report: &[i64], policy: &SafetyPolicy) -> SafetyVerdict {
+    let Some(first) = first_violation(report, policy, policy.sign(report)) else {
+        return SafetyVerdict::Safe;
+    };
+    let removable = signs(report, policy)
+        .into_iter()
+        .filter_map(|sign| {
+            let i = first_violation(report, policy, sign)?;
+            (i..=i + 1)
+                .find(|&removed| first_violation(&without(report, removed), policy, sign).is_none())
+        })
+        .min();
+    match removable {
+        Some(index) => SafetyVerdict::SafeWithout {
+            index,
+            value: report[index],
+        },
+        None => SafetyVerdict::Unsafe {
+            index: first,
+            window: [report[first], report[first + 1]],
+        },
+    }
+}
+
+/// Judges `report` under the default policy, allowing one level to be
+/// removed.
+pub fn verdict(report: &[i64]) -> SafetyVerdict {
+    verdict_with(report, &SafetyPolicy::default())
+}
+
+/// Whether `report` follows `policy` after removing at most one level.
+pub fn can_be_safe_with(report: &[i64], policy: &SafetyPolicy) -> bool {
+    !matches!(verdict_with(report, policy), SafetyVerdict::Unsafe { .. })
+}
+
+/// Whether `x` is safe under the default policy after removing at most one
+/// level.
+pub fn can_be_safe(x: &[i64]) -> bool {
+    can_be_safe_with(x, &SafetyPolicy::default())
+}
+
+/// The fewest levels to remove from `report` so that it follows `policy`,
+/// or `None` if that takes more than `k`.
+///
+/// For every level `i` it computes the fewest removals that keep level `i`
+/// as the last level of a safe prefix. The level kept before it is at most
+/// `k + 1` levels back, so this takes O(n k) time instead of trying all
+/// O(n^k) ways to remove `k` levels.
+pub fn min_removals(report: &[i64], policy: &SafetyPolicy, k: usize) -> Option<usize> {
+    let n = report.len();
+    if n <= 1 {
+        return Some(0);
+    }
+    signs(report, policy)
+        .into_iter()
+        .filter_map(|sign| {
+            // removals[i]: fewest removals before `i` that keep `i`, if at most k
+            let mut removals: Vec<Option<usize>> = vec![None; n];
+            for i in 0..n {
+                let mut best = (i <= k).then_some(i);
+                for j in i.saturating_sub(k + 1)..i {
+                    if let Some(before) = removals[j] {
+                        let total = before + (i - j - 1);
+                        if total <= k && policy.allows_step(report[j], report[i], sign) {
+                            best = Some(best.map_or(total, |best| best.min(total)));
+                        }
+                    }
+                }
+                removals[i] = best;
+            }
+            // every level after the last kept one is removed
+            (n.saturating_sub(k + 1)..n)
+                .filter_map(|i| removals[i].map(|before| before + (n - 1 - i)))
+                .filter(|&total| total <= k)
+                .min()
+        })
+        .min()
+}
+
+/// Whether `report` is safe under the default policy after removing at most
+/// `k` levels.
+pub fn can_be_safe_with_removals(report: &[i64], k: usize) -> bool {
+    min_removals(report, &SafetyPolicy::default(), k).is_some()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(reports: &Self::Input) -> impl Display {
+        reports.iter().filter(|r| is_safe(r)).count()
+    }
+
+    fn part2(reports: &Self::Input) -> Option<impl Display> {
+        Some(reports.iter().filter(|r| can_be_safe(r)).count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_is_safe() {
+        assert!(is_safe(&[7, 6, 4, 2, 1])); // Safe because the levels are all decreasing by 1 or 2.
+        assert!(!is_safe(&[1, 2, 7, 8, 9])); // Unsafe because 2 7 is an increase of 5.
+        assert!(!is_safe(&[9, 7, 6, 2, 1])); // Unsafe because 6 2 is a decrease of 4.
+        assert!(!is_safe(&[1, 3, 2, 4, 5])); // Unsafe because 1 3 is increasing but 3 2 is decreasing.
+        assert!(!is_safe(&[8, 6, 4, 4, 1])); // Unsafe because 4 4 is neither an increase or a decrease.
+        assert!(is_safe(&[1, 3, 6, 7, 9])); // Safe because the levels are all increasing by 1, 2, or 3.
+    }
+
+    #[test]
+    fn test_can_be_safe() {
+        assert!(can_be_safe(&[7, 6, 4, 2, 1])); // Safe as is.
+        assert!(can_be_safe(&[1, 3, 2, 4, 5])); // Removing 3 makes it safe.
+        assert!(can_be_safe(&[5, 4, 2, 3, 1])); // Removing 2 makes it safe.
+        assert!(can_be_safe(&[8, 6, 4, 4, 1])); // Removing 4 makes it safe.
+        assert!(can_be_safe(&[1]));
+        assert!(can_be_safe(&[]));
+        assert!(!can_be_safe(&[1, 2, 7, 8, 9])); // Unsafe because 2 7 is an increase of 5.
+        assert!(!can_be_safe(&[9, 7, 6, 2, 1])); // Unsafe because 6 2 is a decrease of 4.
+        assert!(!can_be_safe(&[1, 3, 2, 5, 4])); // Unsafe because either 3, 2 or 5, 4 will remain.
+    }
+
+    #[test]
+    fn test_can_be_safe_with() {
+        let increasing = SafetyPolicy {
+            trend: Trend::Increasing,
+            ..Default::default()
+        };
+        assert!(can_be_safe_with(&[1, 3, 2, 4, 5], &increasing));
+        assert!(!can_be_safe_with(&[7, 6, 4, 2, 1], &increasing));
+        assert!(can_be_safe(&[9, 1, 2, 3])); // Removing 9 changes the direction.
+
+        let wide = SafetyPolicy {
+            max_step: 5,
+            ..Default::default()
+        };
+        assert!(can_be_safe_with(&[1, 2, 7, 8, 20], &wide));
+        assert!(!can_be_safe_with(&[1, 2, 8, 9, 20], &wide));
+
+        let zigzag = SafetyPolicy {
+            trend: Trend::Any,
+            ..Default::default()
+        };
+        assert!(can_be_safe_with(&[1, 3, 3, 2, 4], &zigzag));
+        assert!(!can_be_safe_with(&[1, 3, 3, 3, 4], &zigzag));
+    }
+
+    #[test]
+    fn test_can_be_safe_with_removals() {
+        assert!(can_be_safe_with_removals(&[7, 6, 4, 2, 1], 0));
+        assert!(!can_be_safe_with_removals(&[1, 3, 2, 4, 5], 0));
+        assert!(can_be_safe_with_removals(&[1, 3, 2, 4, 5], 1));
+        assert!(!can_be_safe_with_removals(&[1, 3, 2, 5, 4], 1));
+        assert!(can_be_safe_with_removals(&[1, 3, 2, 5, 4], 2));
+        assert!(!can_be_safe_with_removals(&[1, 20, 40, 60, 80], 3));
+        assert!(can_be_safe_with_removals(&[1, 20, 40, 60, 80], 4));
+        assert!(can_be_safe_with_removals(&[], 0));
+        assert_eq!(
+            min_removals(&[9, 1, 2, 30, 3, 4], &SafetyPolicy::default(), 5),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_verdict() {
+        assert_eq!(verdict(&[7, 6, 4, 2, 1]), SafetyVerdict::Safe);
+        assert_eq!(
+            verdict(&[1, 3, 2, 4, 5]),
+            SafetyVerdict::SafeWithout { index: 1, value: 3 }
+        );
+        assert_eq!(
+            verdict(&[8, 6, 4, 4, 1]),
+            SafetyVerdict::SafeWithout { index: 2, value: 4 }
+        );
+        assert_eq!(
+            verdict(&[9, 1, 2, 3]),
+            SafetyVerdict::SafeWithout { index: 0, value: 9 }
+        );
+        assert_eq!(
+            verdict(&[1, 2, 7, 8, 9]),
+            SafetyVerdict::Unsafe {
+                index: 1,
+                window: [2, 7]
+            }
+        );
+        assert_eq!(
+            verdict(&[1, 3, 2, 5, 4]).to_string(),
+            "unsafe, 3 2 at index 1 is the first bad step"
+        );
+        assert_eq!(
+            verdict(&[1, 3, 2, 4, 5]).to_string(),
+            "safe without level 3 at index 1"
+        );
+    }
+
+    /// Tries every way to remove at most `k` levels.
+    fn brute_force(report: &[i64], policy: &SafetyPolicy, k: usize) -> bool {
+        if is_safe_with(report, policy) {
+            return true;
+        }
+        k > 0 && (0..report.len()).any(|i| brute_force(&without(report, i), policy, k - 1))
+    }
+
+    fn policy() -> impl Strategy<Value = SafetyPolicy> {
+        let trend = prop_oneof![
+            Just(Trend::Either),
+            Just(Trend::Increasing),
+            Just(Trend::Decreasing),
+            Just(Trend::Any),
+        ];
+        (0..3i64, 0..4i64, any::<bool>(), trend).prop_map(
+            |(min_step, extra, allow_equal, trend)| SafetyPolicy {
+                min_step,
+                max_step: min_step + extra,
+                allow_equal,
+                trend,
+            },
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn prop_removals_match_brute_force(
+            report in prop::collection::vec(0..12i64, 0..9),
+            policy in policy(),
+            k in 0..4usize,
+        ) {
+            prop_assert_eq!(
+                min_removals(&report, &policy, k).is_some(),
+                brute_force(&report, &policy, k)
+            );
+        }
+
+        #[test]
+        fn prop_can_be_safe_matches_brute_force(
+            report in prop::collection::vec(0..12i64, 0..9),
+            policy in policy(),
+        ) {
+            prop_assert_eq!(
+                can_be_safe_with(&report, &policy),
+                brute_force(&report, &policy, 1)
+            );
+            if let SafetyVerdict::SafeWithout { index, .. } = verdict_with(&report, &policy) {
+                prop_assert!(is_safe_with(&without(&report, index), &policy));
+            }
+        }
+    }
+}
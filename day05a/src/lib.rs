@@ -0,0 +1,216 @@
+use aoc_common::{Line, ParseError};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+const DAY: u8 = 5;
+
+/// A page ordering rule `X|Y`: page X must be printed before page Y.
+pub type Rule = (i64, i64);
+/// The page numbers of one update, in print order.
+pub type Update = Vec<i64>;
+
+/// Parses the puzzle input into pairs and updates.
+///
+/// # Arguments
+///
+/// * `input` - Contents of the input file.
+///
+/// # Returns
+///
+/// A tuple containing:
+/// * A vector of `(i64, i64)` pairs.
+/// * A vector of `Vec<i64>` updates.
+///
+/// or a `ParseError` at the first malformed rule or update.
+pub fn parse_input(input: &str) -> Result<(Vec<Rule>, Vec<Update>), ParseError> {
+    let mut lines = Line::all(DAY, input);
+    let mut pairs = Vec::new();
+    let mut updates = Vec::new();
+    // Parse the pairs
+    for line in lines.by_ref().take_while(|line| !line.text.is_empty()) {
+        pairs.push(parse_rule(&line)?);
+    }
+    // Parse the updates
+    for line in lines {
+        updates.push(parse_update(&line)?);
+    }
+    Ok((pairs, updates))
+}
+
+/// Parses a line `X|Y` holding one rule.
+pub fn parse_rule(line: &Line) -> Result<Rule, ParseError> {
+    let (left, right) = line
+        .text
+        .split_once('|')
+        .ok_or_else(|| line.error_at_end("a rule of the form `X|Y`"))?;
+    Ok((
+        line.parse(left, "a page number")?,
+        line.parse(right, "a page number")?,
+    ))
+}
+
+/// Parses a line of comma-separated page numbers holding one update.
+pub fn parse_update(line: &Line) -> Result<Update, ParseError> {
+    line.text
+        .split(',')
+        .map(|num| line.parse(num, "a page number"))
+        .collect()
+}
+
+/// Constructs a dependency map from pairs of dependencies.
+///
+/// # Arguments
+///
+/// * `pairs` - A vector of `(i64, i64)` pairs representing dependencies.
+///
+/// # Returns
+///
+/// A `HashMap` where each key is an item and the value is a `HashSet` of items that depend on the key.
+pub fn dependents(pairs: &[(i64, i64)]) -> HashMap<i64, HashSet<i64>> {
+    let mut result = HashMap::new();
+    for (left, right) in pairs {
+        result
+            .entry(*left)
+            .or_insert_with(HashSet::new)
+            .insert(*right);
+    }
+    result
+}
+
+/// Checks if an update obeys the dependency rules.
+///
+/// # Arguments
+///
+/// * `update` - A vector of `i64` representing the update sequence.
+/// * `dependents` - A `HashMap` of dependencies.
+///
+/// # Returns
+///
+/// `true` if the update obeys the dependency rules, `false` otherwise.
+pub fn update_obeys_deps(update: &[i64], dependents: &HashMap<i64, HashSet<i64>>) -> bool {
+    let mut seen = HashSet::new();
+    for &num in update {
+        if let Some(deps) = dependents.get(&num) {
+            if !deps.is_disjoint(&seen) {
+                return false;
+            }
+        }
+        seen.insert(num);
+    }
+    true
+}
+
+/// A rule `X|Y` that an update breaks by printing page Y before page X.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    pub rule: Rule,
+    /// The index of page X in the update.
+    pub x_index: usize,
+    /// The index of page Y in the update, which is smaller than `x_index`.
+    pub y_index: usize,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (x, y) = self.rule;
+        write!(
+            f,
+            "{}|{}: {} at index {} comes after {} at index {}",
+            x, y, x, self.x_index, y, self.y_index
+        )
+    }
+}
+
+/// Finds every rule that an update breaks.
+///
+/// # Arguments
+///
+/// * `update` - A vector of `i64` representing the update sequence.
+/// * `dependents` - A `HashMap` of dependencies.
+///
+/// # Returns
+///
+/// The broken rules ordered by the index of their page X, then of their
+/// page Y. The update obeys the rules if there are none.
+pub fn violations(update: &[i64], dependents: &HashMap<i64, HashSet<i64>>) -> Vec<Violation> {
+    let mut result = Vec::new();
+    for (x_index, &x) in update.iter().enumerate() {
+        if let Some(deps) = dependents.get(&x) {
+            for (y_index, &y) in update[..x_index].iter().enumerate() {
+                if deps.contains(&y) {
+                    result.push(Violation {
+                        rule: (x, y),
+                        x_index,
+                        y_index,
+                    });
+                }
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let (pairs, updates) = parse_input("47|53\n97|13\n\n75,47,61\n97,13").unwrap();
+        assert_eq!(pairs, vec![(47, 53), (97, 13)]);
+        assert_eq!(updates, vec![vec![75, 47, 61], vec![97, 13]]);
+    }
+
+    #[test]
+    fn test_parse_input_errors() {
+        let err = parse_input("47|53\n97-13\n\n75,47").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(2, 6, "a rule of the form `X|Y`").in_day(5)
+        );
+        let err = parse_input("47|53\n\n75,,47").unwrap_err();
+        assert_eq!(err, ParseError::new(3, 4, "a page number").in_day(5));
+    }
+
+    #[test]
+    fn test_violations() {
+        let deps = dependents(&[(47, 53), (97, 13), (97, 75), (75, 13), (29, 13)]);
+        assert_eq!(violations(&[97, 75, 47, 53], &deps), vec![]);
+        let found = violations(&[13, 75, 29, 97], &deps);
+        assert_eq!(
+            found,
+            vec![
+                Violation {
+                    rule: (75, 13),
+                    x_index: 1,
+                    y_index: 0
+                },
+                Violation {
+                    rule: (29, 13),
+                    x_index: 2,
+                    y_index: 0
+                },
+                Violation {
+                    rule: (97, 13),
+                    x_index: 3,
+                    y_index: 0
+                },
+                Violation {
+                    rule: (97, 75),
+                    x_index: 3,
+                    y_index: 1
+                },
+            ]
+        );
+        assert_eq!(
+            found[0].to_string(),
+            "75|13: 75 at index 1 comes after 13 at index 0"
+        );
+        for update in [[97, 75, 47, 53], [13, 75, 29, 97]] {
+            assert_eq!(
+                violations(&update, &deps).is_empty(),
+                update_obeys_deps(&update, &deps)
+            );
+        }
+    }
+}
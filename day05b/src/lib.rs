@@ -0,0 +1,459 @@
+use aoc_common::{ParseError, Solution};
+pub use day05a::{
+    dependents, dependents as graph, parse_input, parse_rule, parse_update, update_obeys_deps,
+    violations, Rule, Update, Violation,
+};
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::error::Error;
+use std::fmt::{self, Display};
+
+/// A cycle in the ordering rules: every page has to be printed before the
+/// next one, and the last page before the first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError {
+    pub cycle: Vec<i64>,
+}
+
+impl fmt::Display for CycleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the ordering rules contain the cycle ")?;
+        for page in &self.cycle {
+            write!(f, "{} -> ", page)?;
+        }
+        write!(f, "{}", self.cycle[0])
+    }
+}
+
+impl Error for CycleError {}
+
+/// Performs a depth-first search on the graph.
+///
+/// # Arguments
+///
+/// * `g` - The graph represented as a `HashMap`.
+/// * `node` - The starting node for the DFS.
+/// * `unvisited` - A mutable reference to a `HashSet` of unvisited nodes.
+/// * `path` - The nodes whose search is in progress, from the start node to `node`.
+/// * `reversed_finishing` - A mutable reference to a `VecDeque` to store the nodes in reverse finishing order.
+///
+/// # Returns
+///
+/// A `CycleError` if an edge leads back to a node on `path`.
+fn dfs(
+    g: &HashMap<i64, HashSet<i64>>,
+    node: i64,
+    unvisited: &mut HashSet<i64>,
+    path: &mut Vec<i64>,
+    reversed_finishing: &mut VecDeque<i64>,
+) -> Result<(), CycleError> {
+    unvisited.remove(&node);
+    path.push(node);
+    if let Some(neighbors) = g.get(&node) {
+        for neighbor in neighbors {
+            if let Some(start) = path.iter().position(|n| n == neighbor) {
+                return Err(CycleError {
+                    cycle: path[start..].to_vec(),
+                });
+            }
+            if unvisited.contains(neighbor) {
+                dfs(g, *neighbor, unvisited, path, reversed_finishing)?;
+            }
+        }
+    }
+    path.pop();
+    reversed_finishing.push_front(node);
+    Ok(())
+}
+
+/// Performs a full depth-first search on the graph.
+///
+/// # Arguments
+///
+/// * `g` - The graph represented as a `HashMap`.
+///
+/// # Returns
+///
+/// A `VecDeque` containing the nodes in reverse finishing order, which is a
+/// topological order, or a `CycleError` with a cycle of the graph.
+pub fn full_dfs(g: &HashMap<i64, HashSet<i64>>) -> Result<VecDeque<i64>, CycleError> {
+    let mut reversed_finishing = VecDeque::new();
+    // pages without rules of their own, e.g. the last page, only appear as neighbours
+    let mut unvisited: HashSet<i64> = g.keys().chain(g.values().flatten()).cloned().collect();
+    while let Some(&next) = unvisited.iter().next() {
+        dfs(
+            g,
+            next,
+            &mut unvisited,
+            &mut Vec::new(),
+            &mut reversed_finishing,
+        )?;
+    }
+    Ok(reversed_finishing)
+}
+
+/// Constructs a subgraph containing only the specified nodes.
+///
+/// # Arguments
+///
+/// * `g` - The original graph represented as a `HashMap`.
+/// * `nodes` - A vector of nodes to include in the subgraph.
+///
+/// # Returns
+///
+/// A `HashMap` representing the subgraph.
+pub fn subgraph(g: &HashMap<i64, HashSet<i64>>, nodes: &[i64]) -> HashMap<i64, HashSet<i64>> {
+    let mut result = HashMap::new();
+    for node in nodes {
+        if let Some(neighbors) = g.get(node) {
+            result.insert(
+                *node,
+                nodes
+                    .iter()
+                    .filter(|n| neighbors.contains(n))
+                    .cloned()
+                    .collect(),
+            );
+        }
+    }
+    result
+}
+
+/// Orders the pages of `update` according to the rules in `g`.
+///
+/// # Returns
+///
+/// The pages in an order that obeys all rules between them, or a
+/// `CycleError` if the rules between them contain a cycle.
+pub fn update_order(
+    g: &HashMap<i64, HashSet<i64>>,
+    update: &[i64],
+) -> Result<VecDeque<i64>, CycleError> {
+    full_dfs(&subgraph(g, update))
+}
+
+/// The corrected order of an update.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SortedUpdate {
+    pub order: Vec<i64>,
+    /// Two pages that the rules allow in either order, if there are any, in
+    /// which case `order` is not the only correct order.
+    pub ambiguous: Option<[i64; 2]>,
+}
+
+impl SortedUpdate {
+    /// Whether `order` is the only order of the pages that obeys the rules.
+    pub fn is_unique(&self) -> bool {
+        self.ambiguous.is_none()
+    }
+}
+
+/// Orders the pages of `update` according to the rules in `g` with Kahn's
+/// algorithm. Of the pages that may come next, the one that comes first in
+/// `update` is taken, so pages that the rules do not order keep their
+/// original order, and the result does not depend on the order of the rules.
+/// The pages of `update` must be distinct.
+///
+/// # Returns
+///
+/// The sorted update, or a `CycleError` if the rules between the pages of
+/// `update` contain a cycle.
+pub fn kahn_order(
+    g: &HashMap<i64, HashSet<i64>>,
+    update: &[i64],
+) -> Result<SortedUpdate, CycleError> {
+    let sub = subgraph(g, update);
+    let index: HashMap<i64, usize> = update
+        .iter()
+        .enumerate()
+        .map(|(i, &page)| (page, i))
+        .collect();
+    let mut in_degree = vec![0; update.len()];
+    for page in sub.values().flatten() {
+        in_degree[index[page]] += 1;
+    }
+    // indices into `update` of the pages whose predecessors are all placed
+    let mut ready: BTreeSet<usize> = (0..update.len()).filter(|&i| in_degree[i] == 0).collect();
+    let mut order = Vec::with_capacity(update.len());
+    let mut ambiguous = None;
+    while let Some(i) = ready.pop_first() {
+        if let (None, Some(&j)) = (ambiguous, ready.first()) {
+            ambiguous = Some([update[i], update[j]]);
+        }
+        order.push(update[i]);
+        for next in sub.get(&update[i]).into_iter().flatten() {
+            let j = index[next];
+            in_degree[j] -= 1;
+            if in_degree[j] == 0 {
+                ready.insert(j);
+            }
+        }
+    }
+    if order.len() < update.len() {
+        // the pages that are left all wait for each other
+        let left: Vec<i64> = update
+            .iter()
+            .filter(|page| !order.contains(page))
+            .cloned()
+            .collect();
+        return Err(full_dfs(&subgraph(g, &left)).expect_err("the pages left contain a cycle"));
+    }
+    Ok(SortedUpdate { order, ambiguous })
+}
+
+/// The line number of update `i` of an input with `rules`: the updates
+/// follow the rules and a blank line.
+pub fn update_line(rules: &[Rule], i: usize) -> usize {
+    rules.len() + 2 + i
+}
+
+/// Writes the rule graph `g` in the Graphviz DOT language, with pages and
+/// rules sorted so that the output only changes when the rules do.
+///
+/// # Arguments
+///
+/// * `g` - The graph represented as a `HashMap`.
+/// * `update` - If given, only the subgraph of the pages of this update is
+///   written. Each page is labelled with its index in the update and in the
+///   corrected order, the graph with both orders, and broken rules are red.
+///
+/// # Returns
+///
+/// The DOT source, e.g. to render with `dot -Tsvg`.
+pub fn to_dot(g: &HashMap<i64, HashSet<i64>>, update: Option<&[i64]>) -> String {
+    let g = match update {
+        Some(update) => subgraph(g, update),
+        None => g.clone(),
+    };
+    let mut pages: Vec<i64> = g.keys().chain(g.values().flatten()).cloned().collect();
+    pages.sort_unstable();
+    pages.dedup();
+    let mut edges: Vec<(i64, i64)> = g
+        .iter()
+        .flat_map(|(&x, ys)| ys.iter().map(move |&y| (x, y)))
+        .collect();
+    edges.sort_unstable();
+
+    let mut out = String::from("digraph rules {\n    rankdir=LR;\n");
+    let Some(update) = update else {
+        for page in pages {
+            out += &format!("    {};\n", page);
+        }
+        for (x, y) in edges {
+            out += &format!("    {} -> {};\n", x, y);
+        }
+        out += "}\n";
+        return out;
+    };
+
+    let join = |pages: &[i64]| {
+        let pages: Vec<String> = pages.iter().map(|page| page.to_string()).collect();
+        pages.join(",")
+    };
+    let corrected = kahn_order(&g, update).map(|sorted| sorted.order);
+    let corrected_label = match &corrected {
+        Ok(order) => join(order),
+        Err(err) => err.to_string(),
+    };
+    out += &format!(
+        "    label=\"original: {}\\ncorrected: {}\";\n",
+        join(update),
+        corrected_label
+    );
+    for (i, &page) in update.iter().enumerate() {
+        let position = match &corrected {
+            Ok(order) => order.iter().position(|&p| p == page).unwrap().to_string(),
+            Err(_) => "?".to_string(),
+        };
+        out += &format!(
+            "    {} [label=\"{}\\n{} -> {}\"];\n",
+            page, page, i, position
+        );
+    }
+    let broken: HashSet<Rule> = violations(update, &g).iter().map(|v| v.rule).collect();
+    for (x, y) in edges {
+        let color = if broken.contains(&(x, y)) {
+            " [color=red]"
+        } else {
+            ""
+        };
+        out += &format!("    {} -> {}{};\n", x, y, color);
+    }
+    out += "}\n";
+    out
+}
+
+pub mod reduction;
+pub mod repair;
+pub mod rules;
+
+/// The rules and updates of the puzzle example, for the tests of every module.
+#[cfg(test)]
+fn example() -> (Vec<Rule>, Vec<Update>) {
+    let example = include_str!("../../day05a/examples/example.txt");
+    parse_input(example.trim_end()).unwrap()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (Vec<(i64, i64)>, Vec<Vec<i64>>);
+
+    /// Parses the input and checks that the rules between the pages of every
+    /// update can be followed, i.e. contain no cycle.
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (pairs, updates) = parse_input(input)?;
+        let g = graph(&pairs);
+        for (i, update) in updates.iter().enumerate() {
+            if let Err(err) = kahn_order(&g, update) {
+                let line = update_line(&pairs, i);
+                return Err(ParseError::new(
+                    line,
+                    1,
+                    format!("an update whose rules have no cycle, but {}", err),
+                )
+                .in_day(5));
+            }
+        }
+        Ok((pairs, updates))
+    }
+
+    fn part1((pairs, updates): &Self::Input) -> impl Display {
+        let deps = dependents(pairs);
+        // add up middle numbers of valid updates
+        updates
+            .iter()
+            .filter(|update| update_obeys_deps(update, &deps))
+            .map(|update| update[update.len() / 2])
+            .sum::<i64>()
+    }
+
+    fn part2((pairs, updates): &Self::Input) -> Option<impl Display> {
+        // add up middle numbers of corrected invalid updates
+        let sum = repair::middle_sum(&graph(pairs), updates, repair::Strategy::Sort);
+        Some(sum.expect("checked by parse"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RULES: &[(i64, i64)] = &[
+        (47, 53),
+        (97, 13),
+        (97, 61),
+        (97, 47),
+        (75, 29),
+        (61, 13),
+        (75, 53),
+        (29, 13),
+        (97, 29),
+        (53, 29),
+        (61, 53),
+        (97, 53),
+        (61, 29),
+        (47, 13),
+        (75, 47),
+        (97, 75),
+        (47, 61),
+        (75, 61),
+        (47, 29),
+        (75, 13),
+        (53, 13),
+    ];
+
+    #[test]
+    fn test_full_dfs() {
+        let order = full_dfs(&graph(RULES)).unwrap();
+        assert_eq!(order.len(), 7);
+        let order: Vec<i64> = order.into();
+        assert!(update_obeys_deps(&order, &graph(RULES)));
+    }
+
+    #[test]
+    fn test_update_order() {
+        let order = update_order(&graph(RULES), &[97, 13, 75, 29, 47]).unwrap();
+        assert_eq!(order, vec![97, 75, 47, 29, 13]);
+    }
+
+    #[test]
+    fn test_cycle() {
+        let g = graph(&[(1, 2), (2, 3), (3, 1), (3, 4)]);
+        let err = full_dfs(&g).unwrap_err();
+        // the cycle may start at any of its pages
+        let start = err.cycle.iter().position(|&page| page == 1).unwrap();
+        let mut cycle = err.cycle.clone();
+        cycle.rotate_left(start);
+        assert_eq!(cycle, vec![1, 2, 3]);
+        assert!(update_order(&g, &[1, 2, 4]).is_ok());
+        let err = update_order(&g, &[2, 3, 1]).unwrap_err();
+        assert_eq!(err.cycle.len(), 3);
+        assert_eq!(
+            CycleError {
+                cycle: vec![1, 2, 3]
+            }
+            .to_string(),
+            "the ordering rules contain the cycle 1 -> 2 -> 3 -> 1"
+        );
+    }
+
+    #[test]
+    fn test_kahn_order() {
+        let g = graph(RULES);
+        let sorted = kahn_order(&g, &[97, 13, 75, 29, 47]).unwrap();
+        assert_eq!(sorted.order, vec![97, 75, 47, 29, 13]);
+        assert!(sorted.is_unique());
+
+        // 1 and 2 are not ordered by the rules and keep their order
+        let g = graph(&[(1, 3), (2, 3)]);
+        let sorted = kahn_order(&g, &[3, 2, 1]).unwrap();
+        assert_eq!(sorted.order, vec![2, 1, 3]);
+        assert_eq!(sorted.ambiguous, Some([2, 1]));
+        assert_eq!(kahn_order(&g, &[1, 3, 2]).unwrap().order, vec![1, 2, 3]);
+        assert_eq!(kahn_order(&g, &[3, 4]).unwrap().order, vec![3, 4]);
+
+        let g = graph(&[(1, 2), (2, 3), (3, 1), (0, 1)]);
+        let err = kahn_order(&g, &[0, 3, 1, 2]).unwrap_err();
+        assert_eq!(err.cycle.len(), 3);
+    }
+
+    #[test]
+    fn test_kahn_order_is_deterministic() {
+        let mut rules = RULES.to_vec();
+        let expected = kahn_order(&graph(&rules), &[61, 13, 29, 75]).unwrap();
+        for _ in 0..rules.len() {
+            rules.rotate_left(1);
+            assert_eq!(
+                kahn_order(&graph(&rules), &[61, 13, 29, 75]).unwrap(),
+                expected
+            );
+        }
+        assert_eq!(expected.order, vec![75, 61, 29, 13]);
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let g = graph(&[(2, 3), (1, 3), (1, 2)]);
+        assert_eq!(
+            to_dot(&g, None),
+            "digraph rules {\n    rankdir=LR;\n    1;\n    2;\n    3;\n    \
+             1 -> 2;\n    1 -> 3;\n    2 -> 3;\n}\n"
+        );
+        assert_eq!(
+            to_dot(&g, Some(&[2, 1])),
+            "digraph rules {\n    rankdir=LR;\n    \
+             label=\"original: 2,1\\ncorrected: 1,2\";\n    \
+             2 [label=\"2\\n0 -> 1\"];\n    1 [label=\"1\\n1 -> 0\"];\n    \
+             1 -> 2 [color=red];\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_cycles() {
+        let err = Day05::parse("1|2\n2|1\n\n1,3\n2,1").unwrap_err();
+        assert_eq!((err.line, err.column), (5, 1));
+        assert!(err
+            .expected
+            .starts_with("an update whose rules have no cycle"));
+    }
+}
@@ -0,0 +1,210 @@
+//! Finds the rules of a rule set that follow from other rules, the malformed
+//! rules that order a page before itself, and the pages of updates that no
+//! rule mentions.
+
+use crate::{graph, update_obeys_deps, Rule};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Display};
+
+/// A rule `X|Z` that follows from a chain of other rules `X|Y`, `Y|Z`, ...
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Redundant {
+    pub rule: Rule,
+    /// The pages of the chain, from X to Z.
+    pub chain: Vec<i64>,
+}
+
+impl Display for Redundant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let chain: Vec<String> = self.chain.iter().map(|page| page.to_string()).collect();
+        write!(
+            f,
+            "{}|{} follows from {}",
+            self.rule.0,
+            self.rule.1,
+            chain.join(" -> ")
+        )
+    }
+}
+
+/// A path of rules in `g` from page `from` to page `to`, found with a
+/// depth-first search.
+fn chain(g: &HashMap<i64, HashSet<i64>>, from: i64, to: i64) -> Option<Vec<i64>> {
+    let mut parent = HashMap::new();
+    let mut stack = vec![from];
+    while let Some(page) = stack.pop() {
+        if page == to {
+            let mut path = vec![to];
+            while let Some(&prev) = parent.get(path.last().unwrap()) {
+                path.push(prev);
+            }
+            path.reverse();
+            return Some(path);
+        }
+        for &next in g.get(&page).into_iter().flatten() {
+            if next != from && !parent.contains_key(&next) {
+                parent.insert(next, page);
+                stack.push(next);
+            }
+        }
+    }
+    None
+}
+
+/// Finds the rules that follow from the others. They are dropped one at a
+/// time in the order of `rules`, each one if the rules that are left still
+/// lead from its X to its Z, so together they can all be dropped. Repeated
+/// rules are redundant as well. Each one is then explained by a chain of the
+/// rules that are left in the end, so no chain goes through a dropped rule.
+/// Rules `X|X` are not redundant but malformed; see `self_loops`.
+///
+/// Without cycles, the rules that are left are the transitive reduction of
+/// the rules, which does not depend on their order. With cycles there may be
+/// several minimal rule sets, and the order decides which one is left.
+///
+/// A rule that follows from a chain through page Y only follows from it for
+/// updates that contain Y, since rules only apply between pages of an
+/// update; see `changed_updates`.
+pub fn redundant_rules(rules: &[Rule]) -> Vec<Redundant> {
+    let mut g = graph(rules);
+    let mut seen = HashSet::new();
+    let mut dropped = Vec::new();
+    for &(x, z) in rules.iter().filter(|(x, z)| x != z) {
+        if !seen.insert((x, z)) {
+            dropped.push((x, z));
+            continue;
+        }
+        g.get_mut(&x).unwrap().remove(&z);
+        if chain(&g, x, z).is_some() {
+            dropped.push((x, z));
+        } else {
+            g.get_mut(&x).unwrap().insert(z);
+        }
+    }
+    dropped
+        .into_iter()
+        .map(|(x, z)| Redundant {
+            rule: (x, z),
+            chain: chain(&g, x, z).expect("dropping a rule keeps every chain"),
+        })
+        .collect()
+}
+
+/// The rules `X|X`, in their original order. A page cannot come before
+/// itself, so these rules are malformed. They are neither redundant nor
+/// dropped by `transitive_reduction`.
+pub fn self_loops(rules: &[Rule]) -> Vec<Rule> {
+    rules.iter().filter(|(x, y)| x == y).cloned().collect()
+}
+
+/// The rules without the redundant ones, in their original order.
+pub fn transitive_reduction(rules: &[Rule]) -> Vec<Rule> {
+    let mut redundant: Vec<Rule> = redundant_rules(rules).iter().map(|r| r.rule).collect();
+    let mut result = Vec::new();
+    // a repeated rule is dropped from its later copies
+    for &rule in rules.iter().rev() {
+        match redundant.iter().position(|&r| r == rule) {
+            Some(i) => {
+                redundant.swap_remove(i);
+            }
+            None => result.push(rule),
+        }
+    }
+    result.reverse();
+    result
+}
+
+/// The pages of `updates` that no rule mentions, in ascending order.
+pub fn pages_without_rules(rules: &[Rule], updates: &[Vec<i64>]) -> Vec<i64> {
+    let ruled: HashSet<i64> = rules.iter().flat_map(|&(x, y)| [x, y]).collect();
+    let mut pages: Vec<i64> = updates
+        .iter()
+        .flatten()
+        .filter(|page| !ruled.contains(page))
+        .cloned()
+        .collect();
+    pages.sort_unstable();
+    pages.dedup();
+    pages
+}
+
+/// The indices of the updates whose verdict differs between `rules` and
+/// `reduced`.
+pub fn changed_updates(rules: &[Rule], reduced: &[Rule], updates: &[Vec<i64>]) -> Vec<usize> {
+    let (g, reduced) = (graph(rules), graph(reduced));
+    (0..updates.len())
+        .filter(|&i| update_obeys_deps(&updates[i], &g) != update_obeys_deps(&updates[i], &reduced))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redundant_rules() {
+        let rules = [(1, 3), (1, 2), (2, 3), (3, 4), (1, 4), (1, 2)];
+        let redundant = redundant_rules(&rules);
+        let found: Vec<Rule> = redundant.iter().map(|r| r.rule).collect();
+        assert_eq!(found, vec![(1, 3), (1, 4), (1, 2)]);
+        assert_eq!(redundant[0].to_string(), "1|3 follows from 1 -> 2 -> 3");
+        assert_eq!(redundant[2].chain, vec![1, 2]);
+        assert_eq!(transitive_reduction(&rules), vec![(1, 2), (2, 3), (3, 4)]);
+    }
+
+    #[test]
+    fn test_reduction_with_cycles() {
+        // every rule of the cycle is needed, the shortcut is not
+        let rules = [(1, 2), (2, 3), (3, 1), (1, 3)];
+        assert_eq!(transitive_reduction(&rules), vec![(1, 2), (2, 3), (3, 1)]);
+    }
+
+    #[test]
+    fn test_chains_use_rules_that_are_left() {
+        // 1|3 is explained before 2|3 is dropped in favour of 2|4 and 4|3
+        let rules = [(1, 3), (1, 2), (2, 3), (2, 4), (4, 3)];
+        let redundant = redundant_rules(&rules);
+        assert_eq!(
+            redundant[0].to_string(),
+            "1|3 follows from 1 -> 2 -> 4 -> 3"
+        );
+        assert_eq!(redundant[1].to_string(), "2|3 follows from 2 -> 4 -> 3");
+    }
+
+    #[test]
+    fn test_self_loops() {
+        let rules = [(1, 1), (1, 2), (2, 3), (1, 3), (1, 1)];
+        let found: Vec<Rule> = redundant_rules(&rules).iter().map(|r| r.rule).collect();
+        assert_eq!(found, vec![(1, 3)]);
+        assert_eq!(self_loops(&rules), vec![(1, 1), (1, 1)]);
+        assert_eq!(
+            transitive_reduction(&rules),
+            vec![(1, 1), (1, 2), (2, 3), (1, 1)]
+        );
+        assert!(self_loops(&[(1, 2)]).is_empty());
+    }
+
+    #[test]
+    fn test_example() {
+        let (rules, updates) = crate::example();
+        // the example orders all 7 pages, which takes a chain of 6 rules
+        let reduced = transitive_reduction(&rules);
+        assert_eq!(reduced.len(), 6);
+        assert_eq!(redundant_rules(&rules).len(), rules.len() - 6);
+        assert!(changed_updates(&rules, &reduced, &updates).is_empty());
+        // 47|29 is only implied through 61 and 53
+        assert_eq!(changed_updates(&rules, &reduced, &[vec![29, 47]]), vec![0]);
+        // every chain is made of rules that are left, e.g. 97|13 does not go
+        // through the redundant 75|13
+        for redundant in redundant_rules(&rules) {
+            for pair in redundant.chain.windows(2) {
+                assert!(reduced.contains(&(pair[0], pair[1])), "{}", redundant);
+            }
+        }
+        assert!(pages_without_rules(&rules, &updates).is_empty());
+        assert_eq!(
+            pages_without_rules(&rules, &[vec![5, 75, 1, 5]]),
+            vec![1, 5]
+        );
+    }
+}
@@ -0,0 +1,350 @@
+//! Repairs an invalid update by moving as few pages as possible, instead of
+//! sorting it from scratch.
+//!
+//! A page has to come before another if a chain of rules between pages of the
+//! update leads from one to the other. Two pages conflict if the update has
+//! them the other way round. A page that conflicts with a later one, which in
+//! turn conflicts with a later one still, also conflicts with that last page,
+//! so conflicts form a partial order. The pages that keep their order are
+//! therefore a largest antichain of conflicts, which by Dilworth's theorem
+//! are the pages left over by a maximum matching of conflicting pairs.
+
+use crate::{kahn_order, subgraph, update_obeys_deps, CycleError};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Display};
+
+/// How an invalid update is repaired.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Strategy {
+    /// Sort the pages with `kahn_order`, as in part 2.
+    #[default]
+    Sort,
+    /// Move as few pages as possible with `minimal_moves`.
+    MinimalMoves,
+}
+
+/// One page taken out of an update and put back elsewhere.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub page: i64,
+    /// The index of the page in the update.
+    pub from: usize,
+    /// The index of the page in the repaired update. It may equal `from`
+    /// when other moved pages make up for the change of place.
+    pub to: usize,
+    /// The closest page before it in the repaired update that is not moved.
+    pub after: Option<i64>,
+    /// The closest page after it in the repaired update that is not moved.
+    pub before: Option<i64>,
+}
+
+impl Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "move {} from index {} to index {}",
+            self.page, self.from, self.to
+        )?;
+        match (self.after, self.before) {
+            (Some(after), Some(before)) => write!(f, ", between {} and {}", after, before),
+            (Some(after), None) => write!(f, ", after {}", after),
+            (None, Some(before)) => write!(f, ", before {}", before),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+/// An update repaired with as few moves as possible.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinimalRepair {
+    pub order: Vec<i64>,
+    /// The pages that were moved, in the order of the update. All other pages
+    /// keep their order.
+    pub moves: Vec<Move>,
+}
+
+/// Repairs `update` by moving the fewest pages needed to obey the rules in
+/// `g`. The pages that are moved are put back where `kahn_order` would put
+/// them, i.e. as early as the rules and the pages that stay allow. The pages
+/// of `update` must be distinct.
+///
+/// # Returns
+///
+/// The repaired update with its moves, or a `CycleError` if the rules between
+/// the pages of `update` contain a cycle.
+pub fn minimal_moves(
+    g: &HashMap<i64, HashSet<i64>>,
+    update: &[i64],
+) -> Result<MinimalRepair, CycleError> {
+    let mut sub = subgraph(g, update);
+    kahn_order(&sub, update)?;
+    let n = update.len();
+    let index: HashMap<i64, usize> = update
+        .iter()
+        .enumerate()
+        .map(|(i, &page)| (page, i))
+        .collect();
+    // before[i][j]: page i has to come before page j
+    let mut before = vec![vec![false; n]; n];
+    for (x, ys) in &sub {
+        for y in ys {
+            before[index[x]][index[y]] = true;
+        }
+    }
+    for k in 0..n {
+        let after_k = before[k].clone();
+        for row in before.iter_mut().filter(|row| row[k]) {
+            for (cell, &after) in row.iter_mut().zip(&after_k) {
+                *cell |= after;
+            }
+        }
+    }
+    // conflicts[i]: the later pages that have to come before page i
+    let conflicts: Vec<Vec<usize>> = (0..n)
+        .map(|i| (i + 1..n).filter(|&j| before[j][i]).collect())
+        .collect();
+    let kept = largest_antichain(&conflicts);
+
+    // chaining the pages that stay keeps their order when sorting
+    for pair in kept.windows(2) {
+        sub.entry(update[pair[0]])
+            .or_default()
+            .insert(update[pair[1]]);
+    }
+    let order = kahn_order(&sub, update)
+        .expect("the pages that stay do not conflict")
+        .order;
+    let stays: Vec<bool> = order
+        .iter()
+        .map(|page| kept.binary_search(&index[page]).is_ok())
+        .collect();
+    let moves = (0..n)
+        .filter(|i| kept.binary_search(i).is_err())
+        .map(|i| {
+            let to = order.iter().position(|&page| page == update[i]).unwrap();
+            Move {
+                page: update[i],
+                from: i,
+                to,
+                after: (0..to).rev().find(|&j| stays[j]).map(|j| order[j]),
+                before: (to + 1..n).find(|&j| stays[j]).map(|j| order[j]),
+            }
+        })
+        .collect();
+    Ok(MinimalRepair { order, moves })
+}
+
+/// A largest set of elements no two of which are related, in ascending
+/// order, of the partial order on `0..conflicts.len()` in which `i` is
+/// related to every element of `conflicts[i]`.
+fn largest_antichain(conflicts: &[Vec<usize>]) -> Vec<usize> {
+    let n = conflicts.len();
+    // matched[j]: the element matched with j as the larger one of a pair
+    let mut matched = vec![None; n];
+    for i in 0..n {
+        augment(conflicts, i, &mut vec![false; n], &mut matched);
+    }
+    // König's theorem: the elements reachable from unmatched ones along
+    // alternating paths give a minimum vertex cover, and the elements that
+    // are in neither side of the cover form the antichain
+    let mut smaller = vec![false; n];
+    let mut larger = vec![false; n];
+    let mut stack: Vec<usize> = (0..n).filter(|i| !matched.contains(&Some(*i))).collect();
+    for &i in &stack {
+        smaller[i] = true;
+    }
+    while let Some(i) = stack.pop() {
+        for &j in &conflicts[i] {
+            if !larger[j] {
+                larger[j] = true;
+                if let Some(k) = matched[j] {
+                    if !smaller[k] {
+                        smaller[k] = true;
+                        stack.push(k);
+                    }
+                }
+            }
+        }
+    }
+    (0..n).filter(|&i| smaller[i] && !larger[i]).collect()
+}
+
+/// Looks for an augmenting path of the matching from the unmatched `i`.
+/// Returns whether one was found and the matching grown along it.
+fn augment(
+    conflicts: &[Vec<usize>],
+    i: usize,
+    seen: &mut [bool],
+    matched: &mut [Option<usize>],
+) -> bool {
+    for &j in &conflicts[i] {
+        if seen[j] {
+            continue;
+        }
+        seen[j] = true;
+        let free = match matched[j] {
+            Some(k) => augment(conflicts, k, seen, matched),
+            None => true,
+        };
+        if free {
+            matched[j] = Some(i);
+            return true;
+        }
+    }
+    false
+}
+
+/// Repairs `update` with `strategy`.
+pub fn repair(
+    g: &HashMap<i64, HashSet<i64>>,
+    update: &[i64],
+    strategy: Strategy,
+) -> Result<Vec<i64>, CycleError> {
+    match strategy {
+        Strategy::Sort => kahn_order(g, update).map(|sorted| sorted.order),
+        Strategy::MinimalMoves => minimal_moves(g, update).map(|repair| repair.order),
+    }
+}
+
+/// The sum of the middle pages of the invalid updates after repairing them
+/// with `strategy`. With `Strategy::Sort` this is the answer of part 2.
+pub fn middle_sum(
+    g: &HashMap<i64, HashSet<i64>>,
+    updates: &[Vec<i64>],
+    strategy: Strategy,
+) -> Result<i64, CycleError> {
+    let mut sum = 0;
+    for update in updates
+        .iter()
+        .filter(|update| !update_obeys_deps(update, g))
+    {
+        let repaired = repair(g, update, strategy)?;
+        sum += repaired[repaired.len() / 2];
+    }
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph;
+
+    /// All orders of `pages`.
+    fn permutations(pages: &[i64]) -> Vec<Vec<i64>> {
+        if pages.is_empty() {
+            return vec![vec![]];
+        }
+        let mut result = Vec::new();
+        for (i, &first) in pages.iter().enumerate() {
+            let mut rest = pages.to_vec();
+            rest.remove(i);
+            for mut order in permutations(&rest) {
+                order.insert(0, first);
+                result.push(order);
+            }
+        }
+        result
+    }
+
+    fn longest_common_subsequence(a: &[i64], b: &[i64]) -> usize {
+        let mut lengths = vec![vec![0; b.len() + 1]; a.len() + 1];
+        for i in 0..a.len() {
+            for j in 0..b.len() {
+                lengths[i + 1][j + 1] = if a[i] == b[j] {
+                    lengths[i][j] + 1
+                } else {
+                    lengths[i][j + 1].max(lengths[i + 1][j])
+                };
+            }
+        }
+        lengths[a.len()][b.len()]
+    }
+
+    #[test]
+    fn test_minimal_moves() {
+        let g = graph(&[(97, 75), (75, 47), (97, 47)]);
+        let repair = minimal_moves(&g, &[75, 97, 47, 61]).unwrap();
+        assert_eq!(repair.order, vec![97, 75, 47, 61]);
+        assert_eq!(
+            repair.moves,
+            vec![Move {
+                page: 75,
+                from: 0,
+                to: 1,
+                after: Some(97),
+                before: Some(47)
+            }]
+        );
+        assert_eq!(
+            repair.moves[0].to_string(),
+            "move 75 from index 0 to index 1, between 97 and 47"
+        );
+        assert!(minimal_moves(&g, &[97, 75, 47]).unwrap().moves.is_empty());
+    }
+
+    #[test]
+    fn test_move_to_the_same_index() {
+        // 29 lands at its old index, but after 47 instead of before it
+        let (rules, _) = crate::example();
+        let repair = minimal_moves(&graph(&rules), &[97, 13, 75, 29, 47]).unwrap();
+        assert_eq!(repair.order, vec![97, 75, 47, 29, 13]);
+        let moved: Vec<String> = repair.moves.iter().map(Move::to_string).collect();
+        assert_eq!(
+            moved,
+            vec![
+                "move 13 from index 1 to index 4, after 47",
+                "move 29 from index 3 to index 3, after 47"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_minimal_moves_follows_chains() {
+        // 3 and 1 conflict through 2, although no rule relates them directly,
+        // so keeping both of them is not enough
+        let g = graph(&[(3, 2), (2, 1)]);
+        let repair = minimal_moves(&g, &[1, 2, 3]).unwrap();
+        assert_eq!(repair.moves.len(), 2);
+        assert!(update_obeys_deps(&repair.order, &g));
+    }
+
+    #[test]
+    fn test_minimal_moves_against_brute_force() {
+        let rules = [(1, 2), (2, 3), (4, 3), (5, 1), (6, 4), (1, 6)];
+        let g = graph(&rules);
+        let pages = [1, 2, 3, 4, 5, 6];
+        let valid: Vec<Vec<i64>> = permutations(&pages)
+            .into_iter()
+            .filter(|order| update_obeys_deps(order, &g))
+            .collect();
+        for update in permutations(&pages) {
+            let repair = minimal_moves(&g, &update).unwrap();
+            assert!(update_obeys_deps(&repair.order, &g), "{:?}", update);
+            let kept = valid
+                .iter()
+                .map(|order| longest_common_subsequence(&update, order))
+                .max()
+                .unwrap();
+            assert_eq!(repair.moves.len(), pages.len() - kept, "{:?}", update);
+        }
+    }
+
+    #[test]
+    fn test_middle_sum() {
+        let (rules, updates) = crate::example();
+        let g = graph(&rules);
+        assert_eq!(middle_sum(&g, &updates, Strategy::Sort), Ok(123));
+        // the example rules order every pair of pages, so no other order is
+        // correct however few pages are moved
+        assert_eq!(middle_sum(&g, &updates, Strategy::MinimalMoves), Ok(123));
+        // moving 5 to the front is enough, but sorting moves 4 first as well
+        let g = graph(&[(1, 2), (2, 3), (4, 3), (5, 1)]);
+        let updates = [vec![1, 2, 4, 3, 5]];
+        assert_eq!(
+            repair(&g, &updates[0], Strategy::Sort),
+            Ok(vec![4, 5, 1, 2, 3])
+        );
+        assert_eq!(middle_sum(&g, &updates, Strategy::Sort), Ok(1));
+        assert_eq!(middle_sum(&g, &updates, Strategy::MinimalMoves), Ok(2));
+    }
+}
@@ -0,0 +1,267 @@
+//! Ordering rules that can grow while updates are being checked, e.g. when
+//! both arrive one line at a time.
+
+use crate::{parse_rule, parse_update, Rule, Violation};
+use aoc_common::{Line, ParseError};
+use std::collections::HashMap;
+
+/// A set of page ids, one bit per id, that grows as ids are inserted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Bits(Vec<u64>);
+
+impl Bits {
+    fn contains(&self, i: usize) -> bool {
+        self.0
+            .get(i / 64)
+            .is_some_and(|word| word & (1 << (i % 64)) != 0)
+    }
+
+    fn insert(&mut self, i: usize) {
+        if self.0.len() <= i / 64 {
+            self.0.resize(i / 64 + 1, 0);
+        }
+        self.0[i / 64] |= 1 << (i % 64);
+    }
+
+    /// The ids in the set, in ascending order.
+    fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.0.iter().enumerate().flat_map(|(i, &word)| {
+            (0..64)
+                .filter(move |bit| word & (1 << bit) != 0)
+                .map(move |bit| i * 64 + bit)
+        })
+    }
+}
+
+/// Page ordering rules with constant-time lookups, to which rules can be
+/// added at any time.
+///
+/// Checking an update takes one lookup per pair of its pages. It only looks
+/// at the rules themselves: rules between pages that are not in an update do
+/// not apply to it, so neither does a chain through such a page, and an order
+/// that obeys every rule between its pages obeys every chain between them.
+/// Whether a chain of rules leads from one page to another is answered by
+/// `must_precede` with a search when asked, rather than by a transitive
+/// closure that every `add_rule` would have to update. The puzzle rules
+/// contain cycles as a whole, so their closure would have almost every page
+/// precede every other one.
+#[derive(Debug, Clone, Default)]
+pub struct OrderingRules {
+    ids: HashMap<i64, usize>,
+    /// rules[id]: the pages that the page with this id has to come before
+    rules: Vec<Bits>,
+    count: usize,
+}
+
+impl OrderingRules {
+    pub fn new() -> OrderingRules {
+        OrderingRules::default()
+    }
+
+    pub fn from_rules(rules: &[Rule]) -> OrderingRules {
+        let mut result = OrderingRules::new();
+        for &rule in rules {
+            result.add_rule(rule);
+        }
+        result
+    }
+
+    fn id(&mut self, page: i64) -> usize {
+        let next = self.ids.len();
+        let id = *self.ids.entry(page).or_insert(next);
+        if id == next {
+            self.rules.push(Bits::default());
+        }
+        id
+    }
+
+    /// Adds the rule `X|Y`. Returns whether it was not known yet.
+    pub fn add_rule(&mut self, (x, y): Rule) -> bool {
+        let (x, y) = (self.id(x), self.id(y));
+        if self.rules[x].contains(y) {
+            return false;
+        }
+        self.rules[x].insert(y);
+        self.count += 1;
+        true
+    }
+
+    /// The number of distinct rules.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Whether there is a rule `X|Y`.
+    pub fn has_rule(&self, (x, y): Rule) -> bool {
+        match (self.ids.get(&x), self.ids.get(&y)) {
+            (Some(&x), Some(&y)) => self.rules[x].contains(y),
+            _ => false,
+        }
+    }
+
+    /// Whether a chain of one or more rules leads from page `x` to page `y`,
+    /// found with a depth-first search over the rules. A page precedes itself
+    /// if it is part of a cycle.
+    pub fn must_precede(&self, x: i64, y: i64) -> bool {
+        let (Some(&x), Some(&y)) = (self.ids.get(&x), self.ids.get(&y)) else {
+            return false;
+        };
+        let mut seen = Bits::default();
+        let mut stack = vec![x];
+        while let Some(id) = stack.pop() {
+            for next in self.rules[id].iter() {
+                if next == y {
+                    return true;
+                }
+                if !seen.contains(next) {
+                    seen.insert(next);
+                    stack.push(next);
+                }
+            }
+        }
+        false
+    }
+
+    /// Finds every rule that `update` breaks, in the order of
+    /// `day05a::violations`, with one lookup per pair of pages.
+    pub fn violations(&self, update: &[i64]) -> Vec<Violation> {
+        let ids: Vec<Option<usize>> = update
+            .iter()
+            .map(|page| self.ids.get(page).copied())
+            .collect();
+        let mut result = Vec::new();
+        for (x_index, &x) in ids.iter().enumerate() {
+            let Some(x) = x else { continue };
+            for (y_index, &y) in ids[..x_index].iter().enumerate() {
+                if y.is_some_and(|y| self.rules[x].contains(y)) {
+                    result.push(Violation {
+                        rule: (update[x_index], update[y_index]),
+                        x_index,
+                        y_index,
+                    });
+                }
+            }
+        }
+        result
+    }
+
+    /// Whether `update` obeys every rule between its pages.
+    pub fn obeys(&self, update: &[i64]) -> bool {
+        self.violations(update).is_empty()
+    }
+
+    /// Handles one line of a stream of rules and updates, which may come in
+    /// any order: a rule `X|Y` is added, an update is checked against the
+    /// rules so far, and a blank line is skipped.
+    ///
+    /// # Returns
+    ///
+    /// The rules that an update breaks, `None` for any other line, or a
+    /// `ParseError` if the line is neither.
+    pub fn feed(&mut self, line: &Line) -> Result<Option<Vec<Violation>>, ParseError> {
+        if line.text.trim().is_empty() {
+            Ok(None)
+        } else if line.text.contains('|') {
+            self.add_rule(parse_rule(line)?);
+            Ok(None)
+        } else {
+            Ok(Some(self.violations(&parse_update(line)?)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dependents;
+
+    #[test]
+    fn test_violations_match_day05a() {
+        let (rules, updates) = crate::example();
+        let ordering = OrderingRules::from_rules(&rules);
+        let deps = dependents(&rules);
+        assert_eq!(ordering.len(), rules.len());
+        for update in &updates {
+            assert_eq!(
+                ordering.violations(update),
+                crate::violations(update, &deps)
+            );
+        }
+        assert!(ordering.obeys(&[75, 47, 61, 53, 29]));
+        assert!(!ordering.obeys(&[75, 97, 47, 61, 53]));
+        assert!(ordering.obeys(&[1, 75, 2]));
+    }
+
+    #[test]
+    fn test_add_rule() {
+        let mut ordering = OrderingRules::new();
+        assert!(ordering.is_empty());
+        assert!(ordering.add_rule((2, 3)));
+        assert!(ordering.add_rule((1, 2)));
+        assert!(!ordering.add_rule((1, 2)));
+        assert_eq!(ordering.len(), 2);
+        assert!(ordering.has_rule((1, 2)));
+        assert!(!ordering.has_rule((1, 3)));
+        assert!(ordering.must_precede(1, 3));
+        assert!(!ordering.must_precede(3, 1));
+        assert!(!ordering.must_precede(1, 1));
+        // no rule relates 3 and 1 directly, and the chain through 2 does not
+        // apply to an update without page 2
+        assert!(ordering.obeys(&[3, 1]));
+        assert!(ordering.add_rule((3, 1)));
+        assert!(ordering.must_precede(2, 2));
+        assert_eq!(ordering.violations(&[1, 3])[0].rule, (3, 1));
+    }
+
+    #[test]
+    fn test_past_64_pages() {
+        let mut ordering = OrderingRules::new();
+        for page in 0..100 {
+            ordering.add_rule((100, page));
+        }
+        assert!(ordering.has_rule((100, 99)));
+        assert!(!ordering.has_rule((99, 100)));
+        let violations = ordering.violations(&[70, 99, 100]);
+        let rules: Vec<Rule> = violations.iter().map(|v| v.rule).collect();
+        assert_eq!(rules, vec![(100, 70), (100, 99)]);
+    }
+
+    #[test]
+    fn test_must_precede_past_64_pages() {
+        let mut ordering = OrderingRules::new();
+        for page in (0..100).rev() {
+            ordering.add_rule((page, page + 1));
+        }
+        assert!(ordering.must_precede(0, 100));
+        assert!(ordering.must_precede(70, 99));
+        assert!(!ordering.must_precede(99, 70));
+        assert!(!ordering.must_precede(0, 101));
+    }
+
+    #[test]
+    fn test_feed() {
+        let mut ordering = OrderingRules::new();
+        let mut feed = |number, text| {
+            ordering.feed(&Line {
+                day: 5,
+                number,
+                text,
+            })
+        };
+        assert_eq!(feed(1, "1,2"), Ok(Some(vec![])));
+        assert_eq!(feed(2, "2|1"), Ok(None));
+        assert_eq!(feed(3, ""), Ok(None));
+        let violations = feed(4, "1,2").unwrap().unwrap();
+        assert_eq!(violations[0].rule, (2, 1));
+        let err = feed(5, "1,x").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Day 5, line 5, column 3: expected a page number"
+        );
+        assert!(feed(6, "1|").is_err());
+    }
+}
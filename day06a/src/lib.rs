@@ -0,0 +1,115 @@
+use aoc_common::{BitGrid, Direction, Grid, ParseError};
+
+pub use aoc_common::Position;
+
+const DAY: u8 = 6;
+
+/// The lab map, `true` where there is an obstacle, and the start position of
+/// the guard.
+pub type Lab = (Grid<bool>, Position);
+
+pub fn parse_input(input: &str) -> Result<Lab, ParseError> {
+    let mut guards = 0;
+    let grid = Grid::parse_with(input, "`.`, `#` or a single `^`", |ch| match ch {
+        '.' | '#' => Some(ch),
+        '^' if guards == 0 => {
+            guards += 1;
+            Some(ch)
+        }
+        _ => None,
+    })
+    .map_err(|err| err.in_day(DAY))?;
+    let start = grid
+        .position(|&ch| ch == '^')
+        .ok_or_else(|| ParseError::new(grid.height + 1, 1, "a guard `^`").in_day(DAY))?;
+    Ok((grid.map(|&ch| ch == '#'), start))
+}
+
+/// Walks from `start` in direction `dir`, turning right in front of obstacles,
+/// and counts the distinct positions visited before leaving the lab.
+pub fn walk(start: Position, dir: Direction, obstacles: &Grid<bool>) -> u64 {
+    let mut visited = BitGrid::new(obstacles.width, obstacles.height);
+    let mut position = start;
+    let mut dir = dir;
+    loop {
+        visited.insert(position);
+        let next_position = dir.step(position);
+        match obstacles.get(next_position) {
+            None => break,
+            Some(true) => dir = dir.turn_right(),
+            Some(false) => position = next_position,
+        }
+    }
+    visited.len() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_parse_input() {
+        let input = "....#.....\n.........#\n..........\n..#.......\n.......#..\n..........\n.#..^.....\n........#.\n#.........\n......#...";
+        let expected_width = 10;
+        let expected_height = 10;
+        let expected_start_position = (4, 6);
+        let mut expected_obstacles = HashSet::new();
+        expected_obstacles.insert((4, 0));
+        expected_obstacles.insert((9, 1));
+        expected_obstacles.insert((2, 3));
+        expected_obstacles.insert((7, 4));
+        expected_obstacles.insert((1, 6));
+        expected_obstacles.insert((8, 7));
+        expected_obstacles.insert((0, 8));
+        expected_obstacles.insert((6, 9));
+
+        let (map, start_position) = parse_input(input).unwrap();
+        let obstacles: HashSet<Position> = map
+            .iter()
+            .filter(|(_, &obstacle)| obstacle)
+            .map(|(pos, _)| pos)
+            .collect();
+
+        assert_eq!((map.width, map.height), (expected_width, expected_height));
+        assert_eq!(start_position, expected_start_position);
+        assert_eq!(obstacles, expected_obstacles);
+    }
+
+    #[test]
+    fn test_parse_input_errors() {
+        let err = parse_input("..#\n.^x").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(2, 3, "`.`, `#` or a single `^`").in_day(6)
+        );
+        let err = parse_input("..#\n.^").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(2, 3, "3 characters per line").in_day(6)
+        );
+        let err = parse_input("..#\n...").unwrap_err();
+        assert_eq!(err, ParseError::new(3, 1, "a guard `^`").in_day(6));
+    }
+
+    #[test]
+    fn test_walk() {
+        let start = (4, 6);
+        let dir = Direction::North;
+        let mut obstacles = Grid::filled(10, 10, false);
+        for pos in [
+            (4, 0),
+            (9, 1),
+            (2, 3),
+            (7, 4),
+            (1, 6),
+            (8, 7),
+            (0, 8),
+            (6, 9),
+        ] {
+            obstacles[pos] = true;
+        }
+
+        assert_eq!(walk(start, dir, &obstacles), 41);
+    }
+}
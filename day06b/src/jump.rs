@@ -0,0 +1,154 @@
+//! Walks the guard one straight segment at a time instead of one cell at a
+//! time, with a table of where each segment ends.
+
+use aoc_common::{BitGrid, Direction, Grid, Position};
+
+/// Marks a segment that leaves the lab.
+const OUTSIDE: u32 = u32::MAX;
+
+/// For every free cell and direction, the cell before the next obstacle in
+/// that direction, so that `walk_straight` becomes a lookup.
+pub struct JumpTable {
+    width: usize,
+    height: usize,
+    /// stops[cell][dir]: the index of the cell where walking stops
+    stops: Vec<[u32; 4]>,
+}
+
+impl JumpTable {
+    pub fn new(obstacles: &Grid<bool>) -> JumpTable {
+        let width = obstacles.width;
+        let index = |(x, y): Position| y as usize * width + x as usize;
+        let mut stops = vec![[OUTSIDE; 4]; obstacles.data.len()];
+        for dir in Direction::all() {
+            // walk every line back from the edge that `dir` leads to: the cell
+            // after an obstacle is where the cells behind it stop
+            let edge = obstacles
+                .positions()
+                .filter(|&pos| !obstacles.in_bounds(dir.step(pos)));
+            for mut pos in edge {
+                let mut stop = OUTSIDE;
+                let mut after_obstacle = false;
+                while let Some(&blocked) = obstacles.get(pos) {
+                    if blocked {
+                        after_obstacle = true;
+                    } else {
+                        if after_obstacle {
+                            stop = index(pos) as u32;
+                            after_obstacle = false;
+                        }
+                        stops[index(pos)][dir as usize] = stop;
+                    }
+                    pos = dir.reverse().step(pos);
+                }
+            }
+        }
+        JumpTable {
+            width,
+            height: obstacles.height,
+            stops,
+        }
+    }
+
+    /// `walk_straight` from the free cell `start`, with one more obstacle at
+    /// `block` if that is given.
+    pub fn jump(
+        &self,
+        start: Position,
+        dir: Direction,
+        block: Option<Position>,
+    ) -> Option<Position> {
+        let stop = self.stops[start.1 as usize * self.width + start.0 as usize][dir as usize];
+        let stop = (stop != OUTSIDE).then(|| {
+            let stop = stop as usize;
+            ((stop % self.width) as i64, (stop / self.width) as i64)
+        });
+        let Some(block) = block else {
+            return stop;
+        };
+        // how many steps ahead the block is, if it is on the way at all
+        let (dx, dy) = dir.delta();
+        let ahead = if dir.is_horizontal() {
+            (block.1 == start.1).then_some((block.0 - start.0) * dx)
+        } else {
+            (block.0 == start.0).then_some((block.1 - start.1) * dy)
+        };
+        let reach = stop.map(|(x, y)| (x - start.0).abs() + (y - start.1).abs());
+        match ahead {
+            Some(ahead) if ahead > 0 && reach.is_none_or(|reach| ahead <= reach) => {
+                Some((block.0 - dx, block.1 - dy))
+            }
+            _ => stop,
+        }
+    }
+
+    /// `walk` with `jump` instead of `walk_straight`.
+    pub fn walk(
+        &self,
+        start: Position,
+        mut dir: Direction,
+        block: Option<Position>,
+    ) -> (Position, bool) {
+        let mut position = start;
+        let mut corner_points = vec![BitGrid::new(self.width, self.height); 4];
+        while let Some(next) = self.jump(position, dir, block) {
+            if !corner_points[dir as usize].insert(position) {
+                return (position, true);
+            }
+            position = next;
+            dir = dir.turn_right();
+        }
+        (position, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::walk_straight;
+
+    const EXAMPLE: &str = "....#.....\n.........#\n..........\n..#.......\n.......#..\n..........\n.#..^.....\n........#.\n#.........\n......#...";
+
+    #[test]
+    fn test_jump_matches_walk_straight() {
+        let (mut obstacles, _) = day06a::parse_input(EXAMPLE).unwrap();
+        let table = JumpTable::new(&obstacles);
+        let free: Vec<Position> = obstacles
+            .positions()
+            .filter(|&pos| !obstacles[pos])
+            .collect();
+        for &start in &free {
+            for dir in Direction::all() {
+                let expected = walk_straight(start, dir, &obstacles);
+                assert_eq!(
+                    table.jump(start, dir, None),
+                    expected,
+                    "{:?} {:?}",
+                    start,
+                    dir
+                );
+                for &block in free.iter().filter(|&&block| block != start) {
+                    obstacles[block] = true;
+                    let expected = walk_straight(start, dir, &obstacles);
+                    obstacles[block] = false;
+                    assert_eq!(
+                        table.jump(start, dir, Some(block)),
+                        expected,
+                        "{:?} {:?} {:?}",
+                        start,
+                        dir,
+                        block
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_walk() {
+        let (obstacles, start) = day06a::parse_input(EXAMPLE).unwrap();
+        let table = JumpTable::new(&obstacles);
+        assert_eq!(table.walk(start, Direction::North, None), ((7, 7), false));
+        assert!(table.walk(start, Direction::North, Some((3, 6))).1);
+    }
+}
@@ -0,0 +1,302 @@
+use aoc_common::{BitGrid, Direction, Grid, ParseError, Position, Solution};
+use day06a::{parse_input, Lab};
+use rayon::prelude::*;
+use std::collections::HashSet;
+use std::fmt::Display;
+
+pub mod jump;
+
+use jump::JumpTable;
+
+/// given starting point and direction compute position where one hits next obstacle
+/// None if out of bounds
+pub fn walk_straight(start: Position, dir: Direction, obstacles: &Grid<bool>) -> Option<Position> {
+    let mut position = start;
+    loop {
+        let next = dir.step(position);
+        match obstacles.get(next) {
+            None => return None,
+            Some(true) => return Some(position),
+            Some(false) => position = next,
+        }
+    }
+}
+
+/// Given start position and direction, walk until cycle is detected or out of bounds
+/// If cycle is detected returned position is in cycle
+/// If out of bounds returned position is position from which one went straight out of bounds
+pub fn walk(start: Position, mut dir: Direction, obstacles: &Grid<bool>) -> (Position, bool) {
+    let mut position = start;
+    // one set of corner points per direction
+    let mut corner_points = vec![BitGrid::new(obstacles.width, obstacles.height); 4];
+    while let Some(next) = walk_straight(position, dir, obstacles) {
+        if corner_points[dir as usize].contains(position) {
+            return (position, true);
+        }
+        corner_points[dir as usize].insert(position);
+        position = next;
+        dir = dir.turn_right();
+    }
+    (position, false)
+}
+
+/// Whether the candidate obstacles of `obstacles_for_cycle_with` are tried
+/// one after another or in parallel on the rayon thread pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Execution {
+    #[default]
+    Sequential,
+    Parallel,
+}
+
+/// Given start position and direction, compute where along the way exactly one obstacle
+/// could be placed to create lead to a cycle
+pub fn obstacles_for_cycle(
+    start: Position,
+    dir: Direction,
+    obstacles: Grid<bool>,
+) -> HashSet<Position> {
+    obstacles_for_cycle_with(start, dir, &obstacles, Execution::Sequential)
+}
+
+/// `obstacles_for_cycle` with the candidates tried as `execution` says. The
+/// obstacles are shared between the walks and never changed, since the jump
+/// table takes the candidate obstacle as an argument, so both give the same
+/// positions.
+pub fn obstacles_for_cycle_with(
+    start: Position,
+    dir: Direction,
+    obstacles: &Grid<bool>,
+    execution: Execution,
+) -> HashSet<Position> {
+    let table = JumpTable::new(obstacles);
+    let candidates = candidates(start, dir, obstacles);
+    let cycle = |&(position, dir, block): &Candidate| table.walk(position, dir, Some(block)).1;
+    match execution {
+        Execution::Sequential => candidates
+            .iter()
+            .filter(|candidate| cycle(candidate))
+            .map(|&(_, _, block)| block)
+            .collect(),
+        Execution::Parallel => candidates
+            .par_iter()
+            .filter(|candidate| cycle(candidate))
+            .map(|&(_, _, block)| block)
+            .collect(),
+    }
+}
+
+/// `obstacles_for_cycle` without the jump table, walking every candidate cell
+/// by cell. Kept to check and benchmark the jump table against.
+pub fn obstacles_for_cycle_stepping(
+    start: Position,
+    dir: Direction,
+    mut obstacles: Grid<bool>,
+) -> HashSet<Position> {
+    let mut obstacles_for_cycle = HashSet::new();
+    for (position, dir, block) in candidates(start, dir, &obstacles) {
+        obstacles[block] = true;
+        let (_, cycle) = walk(position, dir, &obstacles);
+        obstacles[block] = false;
+        if cycle {
+            obstacles_for_cycle.insert(block);
+        }
+    }
+    obstacles_for_cycle
+}
+
+/// A cell in front of the guard where an obstacle might make it walk in a
+/// cycle: the guard's position, its direction after turning at the obstacle,
+/// and the cell.
+type Candidate = (Position, Direction, Position);
+
+/// Walks the guard's path and collects the cells in front of it that it has
+/// not been on yet, which are the cells that could take an obstacle.
+fn candidates(start: Position, dir: Direction, obstacles: &Grid<bool>) -> Vec<Candidate> {
+    let mut visited = BitGrid::new(obstacles.width, obstacles.height);
+    let mut candidates = Vec::new();
+    let mut position = start;
+    let mut dir = dir;
+    loop {
+        visited.insert(position);
+        let in_front = dir.step(position);
+        match obstacles.get(in_front) {
+            None => break,
+            Some(true) => dir = dir.turn_right(),
+            Some(false) => {
+                if !visited.contains(in_front) {
+                    // optimization: if we already visited position with dir its a cycle
+                    // but there are other ways in which one can get cycle
+                    candidates.push((position, dir.turn_right(), in_front));
+                }
+                position = in_front;
+            }
+        }
+    }
+    candidates
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Lab;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((obstacles, start): &Self::Input) -> impl Display {
+        day06a::walk(*start, Direction::North, obstacles)
+    }
+
+    fn part2((obstacles, start): &Self::Input) -> Option<impl Display> {
+        let cycle_obstacles =
+            obstacles_for_cycle_with(*start, Direction::North, obstacles, Execution::Sequential);
+        Some(cycle_obstacles.len())
+    }
+}
+
+/// `Day06` with the candidates of part 2 tried in parallel.
+pub struct Day06Parallel;
+
+impl Solution for Day06Parallel {
+    type Input = Lab;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Day06::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        Day06::part1(input)
+    }
+
+    fn part2((obstacles, start): &Self::Input) -> Option<impl Display> {
+        let cycle_obstacles =
+            obstacles_for_cycle_with(*start, Direction::North, obstacles, Execution::Parallel);
+        Some(cycle_obstacles.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn obstacle_map(width: usize, height: usize, obstacles: &HashSet<Position>) -> Grid<bool> {
+        let mut map = Grid::filled(width, height, false);
+        for &pos in obstacles {
+            map[pos] = true;
+        }
+        map
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let input = "....#.....\n.........#\n..........\n..#.......\n.......#..\n..........\n.#..^.....\n........#.\n#.........\n......#...";
+        let expected_width = 10;
+        let expected_height = 10;
+        let expected_start_position = (4, 6);
+        let mut expected_obstacles = HashSet::new();
+        expected_obstacles.insert((4, 0));
+        expected_obstacles.insert((9, 1));
+        expected_obstacles.insert((2, 3));
+        expected_obstacles.insert((7, 4));
+        expected_obstacles.insert((1, 6));
+        expected_obstacles.insert((8, 7));
+        expected_obstacles.insert((0, 8));
+        expected_obstacles.insert((6, 9));
+
+        let (map, start_position) = parse_input(input).unwrap();
+
+        assert_eq!((map.width, map.height), (expected_width, expected_height));
+        assert_eq!(start_position, expected_start_position);
+        assert_eq!(
+            map,
+            obstacle_map(expected_width, expected_height, &expected_obstacles)
+        );
+    }
+
+    #[test]
+    fn walk_test() {
+        let expected_width = 10;
+        let expected_height = 10;
+        let expected_start_position = (4, 6);
+        let mut expected_obstacles = HashSet::new();
+        expected_obstacles.insert((4, 0));
+        expected_obstacles.insert((9, 1));
+        expected_obstacles.insert((2, 3));
+        expected_obstacles.insert((7, 4));
+        expected_obstacles.insert((1, 6));
+        expected_obstacles.insert((8, 7));
+        expected_obstacles.insert((0, 8));
+        expected_obstacles.insert((6, 9));
+        assert_eq!(
+            walk(
+                expected_start_position,
+                Direction::North,
+                &obstacle_map(expected_width, expected_height, &expected_obstacles)
+            ),
+            ((7, 7), false)
+        );
+    }
+
+    #[test]
+    fn walk_test_cycle() {
+        let expected_width = 4;
+        let expected_height = 4;
+        let expected_start_position = (1, 2);
+        let mut expected_obstacles = HashSet::new();
+        expected_obstacles.insert((0, 2));
+        expected_obstacles.insert((1, 0));
+        expected_obstacles.insert((2, 3));
+        expected_obstacles.insert((3, 1));
+        assert_eq!(
+            walk(
+                expected_start_position,
+                Direction::North,
+                &obstacle_map(expected_width, expected_height, &expected_obstacles)
+            ),
+            ((1, 2), true)
+        );
+    }
+
+    #[test]
+    fn obstacles_for_cycle_test() {
+        let expected_width = 10;
+        let expected_height = 10;
+        let expected_start_position = (4, 6);
+        let mut expected_obstacles = HashSet::new();
+        expected_obstacles.insert((4, 0));
+        expected_obstacles.insert((9, 1));
+        expected_obstacles.insert((2, 3));
+        expected_obstacles.insert((7, 4));
+        expected_obstacles.insert((1, 6));
+        expected_obstacles.insert((8, 7));
+        expected_obstacles.insert((0, 8));
+        expected_obstacles.insert((6, 9));
+        let obstacles = obstacle_map(expected_width, expected_height, &expected_obstacles);
+        dbg!(obstacles_for_cycle(
+            expected_start_position,
+            Direction::North,
+            obstacles.clone()
+        ));
+        assert_eq!(
+            obstacles_for_cycle(expected_start_position, Direction::North, obstacles.clone()).len(),
+            6
+        );
+        let parallel = obstacles_for_cycle_with(
+            expected_start_position,
+            Direction::North,
+            &obstacles,
+            Execution::Parallel,
+        );
+        assert_eq!(
+            parallel,
+            obstacles_for_cycle(expected_start_position, Direction::North, obstacles.clone())
+        );
+        assert_eq!(
+            obstacles_for_cycle_stepping(expected_start_position, Direction::North, obstacles)
+                .len(),
+            6
+        );
+    }
+}
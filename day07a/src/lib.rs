@@ -1,41 +1,38 @@
+use aoc_common::{Line, ParseError};
 use std::collections::HashSet;
 
-struct Equation {
-    test_value: i64,
-    numbers: Vec<i64>,
+const DAY: u8 = 7;
+
+#[derive(Debug)]
+pub struct Equation {
+    pub test_value: i64,
+    pub numbers: Vec<i64>,
 }
 
-// the grid dimensions, the start position and the positions of obstacles
-fn parse_input(input: &[u8]) -> Vec<Equation> {
-    let input_str = std::str::from_utf8(input).expect("Invalid UTF-8 sequence");
-    input_str
-        .lines()
+/// Parses one equation per line of the form `test_value: n1 n2 ...`.
+pub fn parse_input(input: &str) -> Result<Vec<Equation>, ParseError> {
+    Line::all(DAY, input)
         .map(|line| {
-            let mut parts = line.split(": ");
-            let test_value = parts
-                .next()
-                .unwrap()
-                .parse::<i64>()
-                .expect("Invalid test value");
-            let numbers = parts
-                .next()
-                .unwrap()
-                .split_whitespace()
-                .map(|num| num.parse::<i64>().expect("Invalid number"))
-                .collect();
-            Equation {
-                test_value,
-                numbers,
-            }
+            let (test_value, numbers) = line
+                .text
+                .split_once(": ")
+                .ok_or_else(|| line.error_at_end("`: ` after the test value"))?;
+            Ok(Equation {
+                test_value: line.parse(test_value, "a test value")?,
+                numbers: numbers
+                    .split_whitespace()
+                    .map(|num| line.parse(num, "a number"))
+                    .collect::<Result<_, _>>()?,
+            })
         })
         .collect()
 }
 
-fn is_solvable(eq: &Equation) -> bool {
+pub fn is_solvable(eq: &Equation) -> bool {
     values_le_test_value(eq).contains(&eq.test_value)
 }
 
-fn values_le_test_value(eq: &Equation) -> HashSet<i64> {
+pub fn values_le_test_value(eq: &Equation) -> HashSet<i64> {
     eq.numbers.iter().fold(HashSet::new(), |mut values, &num| {
         if values.is_empty() {
             values.insert(num);
@@ -50,25 +47,15 @@ fn values_le_test_value(eq: &Equation) -> HashSet<i64> {
     })
 }
 
-fn main() {
-    let bytes = include_bytes!("../input.txt");
-    let equations = parse_input(bytes);
-    let result: i64 = equations
-        .iter()
-        .filter(|eq| is_solvable(eq))
-        .map(|eq| eq.test_value)
-        .sum();
-    println!("{}", result);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_common::input::puzzle_input;
 
     #[test]
     fn test_parse_input() {
-        let input = b"190: 10 19\n3267: 81 40 27\n83: 17 5\n156: 15 6\n7290: 6 8 6 15\n161011: 16 10 13\n192: 17 8 14\n21037: 9 7 18 13\n292: 11 6 16 20";
-        let equations = parse_input(input);
+        let input = "190: 10 19\n3267: 81 40 27\n83: 17 5\n156: 15 6\n7290: 6 8 6 15\n161011: 16 10 13\n192: 17 8 14\n21037: 9 7 18 13\n292: 11 6 16 20";
+        let equations = parse_input(input).unwrap();
 
         assert_eq!(equations.len(), 9);
         assert_eq!(equations[0].test_value, 190);
@@ -91,6 +78,17 @@ mod tests {
         assert_eq!(equations[8].numbers, vec![11, 6, 16, 20]);
     }
 
+    #[test]
+    fn test_parse_input_errors() {
+        let err = parse_input("190: 10 19\n3267 81 40").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(2, 11, "`: ` after the test value").in_day(7)
+        );
+        let err = parse_input("190: 10 1x9").unwrap_err();
+        assert_eq!(err, ParseError::new(1, 9, "a number").in_day(7));
+    }
+
     #[test]
     fn test_values() {
         let eq = Equation {
@@ -105,8 +103,10 @@ mod tests {
 
     #[test]
     fn test_result() {
-        let bytes = include_bytes!("../input.txt");
-        let equations = parse_input(bytes);
+        let Some(input) = puzzle_input(7) else {
+            return;
+        };
+        let equations = parse_input(&input).unwrap();
         let result: i64 = equations
             .iter()
             .filter(|eq| is_solvable(eq))
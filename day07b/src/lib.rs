@@ -1,41 +1,14 @@
+use aoc_common::{ParseError, Solution};
 use std::collections::HashSet;
+use std::fmt::Display;
 
-struct Equation {
-    test_value: i64,
-    numbers: Vec<i64>,
-}
+pub use day07a::{parse_input, Equation};
 
-// the grid dimensions, the start position and the positions of obstacles
-fn parse_input(input: &[u8]) -> Vec<Equation> {
-    let input_str = std::str::from_utf8(input).expect("Invalid UTF-8 sequence");
-    input_str
-        .lines()
-        .map(|line| {
-            let mut parts = line.split(": ");
-            let test_value = parts
-                .next()
-                .unwrap()
-                .parse::<i64>()
-                .expect("Invalid test value");
-            let numbers = parts
-                .next()
-                .unwrap()
-                .split_whitespace()
-                .map(|num| num.parse::<i64>().expect("Invalid number"))
-                .collect();
-            Equation {
-                test_value,
-                numbers,
-            }
-        })
-        .collect()
-}
-
-fn is_solvable(eq: &Equation) -> bool {
+pub fn is_solvable(eq: &Equation) -> bool {
     values_le_test_value(eq).contains(&eq.test_value)
 }
 
-fn concat(l: i64, r: i64) -> i64 {
+pub fn concat(l: i64, r: i64) -> i64 {
     let mut shift = 1;
     let mut rc = r;
     while rc >= 10 {
@@ -45,7 +18,13 @@ fn concat(l: i64, r: i64) -> i64 {
     l * shift * 10 + r
 }
 
-fn values_le_test_value(eq: &Equation) -> HashSet<i64> {
+/// Same as `concat`, but computes the shift from the number of decimal digits of `r`.
+pub fn concat2(l: i64, r: i64) -> i64 {
+    let digits = if r == 0 { 1 } else { r.ilog10() + 1 };
+    l * 10i64.pow(digits) + r
+}
+
+pub fn values_le_test_value(eq: &Equation) -> HashSet<i64> {
     eq.numbers.iter().fold(HashSet::new(), |mut values, &num| {
         if values.is_empty() {
             values.insert(num);
@@ -60,29 +39,42 @@ fn values_le_test_value(eq: &Equation) -> HashSet<i64> {
     })
 }
 
-fn main() {
-    let bytes = include_bytes!("../input.txt");
-    let equations = parse_input(bytes);
-    // time the call
-    let start = std::time::Instant::now();
-    let result: i64 = equations
-        .iter()
-        .filter(|eq| is_solvable(eq))
-        .map(|eq| eq.test_value)
-        .sum();
-    let elapsed = start.elapsed();
-    println!("Time: {:?}", elapsed);
-    println!("{}", result);
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<Equation>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(equations: &Self::Input) -> impl Display {
+        equations
+            .iter()
+            .filter(|eq| day07a::is_solvable(eq))
+            .map(|eq| eq.test_value)
+            .sum::<i64>()
+    }
+
+    fn part2(equations: &Self::Input) -> Option<impl Display> {
+        let result = equations
+            .iter()
+            .filter(|eq| is_solvable(eq))
+            .map(|eq| eq.test_value)
+            .sum::<i64>();
+        Some(result)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_common::input::puzzle_input;
 
     #[test]
     fn test_parse_input() {
-        let input = b"190: 10 19\n3267: 81 40 27\n83: 17 5\n156: 15 6\n7290: 6 8 6 15\n161011: 16 10 13\n192: 17 8 14\n21037: 9 7 18 13\n292: 11 6 16 20";
-        let equations = parse_input(input);
+        let input = "190: 10 19\n3267: 81 40 27\n83: 17 5\n156: 15 6\n7290: 6 8 6 15\n161011: 16 10 13\n192: 17 8 14\n21037: 9 7 18 13\n292: 11 6 16 20";
+        let equations = parse_input(input).unwrap();
 
         assert_eq!(equations.len(), 9);
         assert_eq!(equations[0].test_value, 190);
@@ -119,8 +111,10 @@ mod tests {
 
     #[test]
     fn test_result() {
-        let bytes = include_bytes!("../input.txt");
-        let equations = parse_input(bytes);
+        let Some(input) = puzzle_input(7) else {
+            return;
+        };
+        let equations = parse_input(&input).unwrap();
         let result: i64 = equations
             .iter()
             .filter(|eq| is_solvable(eq))
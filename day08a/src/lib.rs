@@ -0,0 +1,78 @@
+use aoc_common::{Grid, ParseError};
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+pub use aoc_common::Position;
+
+const DAY: u8 = 8;
+
+/// The positions of the antennas of each frequency.
+pub type Antennas = HashMap<char, HashSet<Position>>;
+
+/// Parses the antenna map into the positions of the antennas of each
+/// frequency, and the map itself.
+pub fn parse_input(input: &str) -> Result<(Antennas, Grid), ParseError> {
+    let grid = Grid::parse_with(input, "`.` or an antenna frequency", |c| {
+        (c == '.' || c.is_ascii_alphanumeric()).then_some(c)
+    })
+    .map_err(|err| err.in_day(DAY))?;
+    let mut antennas: Antennas = HashMap::new();
+    for (pos, &c) in grid.iter() {
+        if c != '.' {
+            antennas.entry(c).or_default().insert(pos);
+        }
+    }
+    Ok((antennas, grid))
+}
+
+pub fn antinodes(a0: &Position, a1: &Position) -> (Position, Position) {
+    let diff = (a1.0 - a0.0, a1.1 - a0.1);
+    let antinode1 = (a0.0 - diff.0, a0.1 - diff.1); // First antinode
+    let antinode2 = (a1.0 + diff.0, a1.1 + diff.1); // Second antinode
+    (antinode1, antinode2) // Return both antinodes as a tuple
+}
+
+pub fn find_antinodes(antennas: &Antennas, grid: &Grid) -> HashSet<Position> {
+    let mut all_antinodes = HashSet::new();
+
+    for (_, positions) in antennas.iter() {
+        for pair in positions.iter().combinations(2) {
+            let (a0, a1) = (pair[0], pair[1]);
+            let (antinode1, antinode2) = antinodes(a0, a1);
+            if grid.in_bounds(antinode1) {
+                all_antinodes.insert(antinode1);
+            }
+            if grid.in_bounds(antinode2) {
+                all_antinodes.insert(antinode2);
+            }
+        }
+    }
+    all_antinodes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let (antennas, grid) = parse_input("..a.\n.A.a").unwrap();
+        assert_eq!((grid.width, grid.height), (4, 2));
+        assert_eq!(antennas[&'a'], HashSet::from([(2, 0), (3, 1)]));
+        assert_eq!(antennas[&'A'], HashSet::from([(1, 1)]));
+    }
+
+    #[test]
+    fn test_parse_input_errors() {
+        let err = parse_input("..a.\n.A#a").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(2, 3, "`.` or an antenna frequency").in_day(8)
+        );
+        let err = parse_input("..a.\n.A.").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(2, 4, "4 characters per line").in_day(8)
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use aoc_common::{Grid, ParseError, Solution};
+use itertools::Itertools;
+use num::Integer;
+use std::collections::HashSet;
+use std::fmt::Display;
+
+pub use day08a::{parse_input, Antennas, Position};
+
+pub fn trace_line(
+    start: Position,
+    direction: (i64, i64),
+    positions: &mut HashSet<Position>,
+    grid: &Grid,
+) {
+    let mut current = start;
+    while grid.in_bounds(current) {
+        positions.insert(current);
+        current = (current.0 + direction.0, current.1 + direction.1);
+    }
+}
+
+pub fn antinodes(a0: Position, a1: Position, grid: &Grid) -> HashSet<Position> {
+    let mut antis = HashSet::new();
+    let diff = (a1.0 - a0.0, a1.1 - a0.1);
+    let gcd = diff.0.abs().gcd(&diff.1.abs());
+    let step_x = diff.0 / gcd;
+    let step_y = diff.1 / gcd;
+    trace_line(a0, (step_x, step_y), &mut antis, grid);
+    trace_line(a0, (-step_x, -step_y), &mut antis, grid);
+    antis
+}
+
+pub fn find_antinodes(antennas: &Antennas, grid: &Grid) -> HashSet<Position> {
+    let mut all_antinodes = HashSet::new();
+    for (_, positions) in antennas.iter() {
+        for pair in positions.iter().combinations(2) {
+            all_antinodes.extend(antinodes(*pair[0], *pair[1], grid));
+        }
+    }
+    all_antinodes
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = (Antennas, Grid);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((antennas, grid): &Self::Input) -> impl Display {
+        day08a::find_antinodes(antennas, grid).len()
+    }
+
+    fn part2((antennas, grid): &Self::Input) -> Option<impl Display> {
+        Some(find_antinodes(antennas, grid).len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::input::puzzle_input;
+
+    #[test]
+    fn test_calculate_antinodes() {
+        let Some(input) = puzzle_input(8) else {
+            return;
+        };
+        let (antennas, grid) = parse_input(&input).unwrap();
+        let antis = find_antinodes(&antennas, &grid);
+        let expected_len = 1277; // Replace with the actual expected result for this input.
+        assert_eq!(
+            antis.len(),
+            expected_len,
+            "Antinode count does not match expected"
+        );
+    }
+}
@@ -1,48 +1,24 @@
-use core::hash;
-use std::{alloc::handle_alloc_error, cmp::min};
+use aoc_common::{Line, ParseError, Solution};
+use std::cmp::min;
+use std::fmt::Display;
 
-struct Equation {
-    test_value: i64,
-    numbers: Vec<i64>,
-}
-
-// the grid dimensions, the start position and the positions of obstacles
-fn parse_input(input: &[u8]) -> Vec<Equation> {
-    let input_str = std::str::from_utf8(input).expect("Invalid UTF-8 sequence");
-    input_str
-        .lines()
-        .map(|line| {
-            let mut parts = line.split(": ");
-            let test_value = parts
-                .next()
-                .unwrap()
-                .parse::<i64>()
-                .expect("Invalid test value");
-            let numbers = parts
-                .next()
-                .unwrap()
-                .split_whitespace()
-                .map(|num| num.parse::<i64>().expect("Invalid number"))
-                .collect();
-            Equation {
-                test_value,
-                numbers,
-            }
-        })
-        .collect()
-}
+const DAY: u8 = 9;
 
-fn string_to_digits(input: &[u8]) -> Vec<u8> {
+/// Parses the disk map, a string of single-digit block counts.
+pub fn string_to_digits(input: &str) -> Result<Vec<u8>, ParseError> {
     let mut digits = Vec::new();
-    for &byte in input {
-        if byte.is_ascii_digit() {
-            digits.push(byte - b'0');
+    for line in Line::all(DAY, input) {
+        for (x, ch) in line.text.chars().enumerate() {
+            match ch.to_digit(10) {
+                Some(digit) => digits.push(digit as u8),
+                None => return Err(line.error_at_column(x + 1, "a digit")),
+            }
         }
     }
-    digits
+    Ok(digits)
 }
 
-fn cksum(input: &[u8]) -> usize {
+pub fn cksum(input: &[u8]) -> usize {
     if input.len() <= 2 {
         return 0;
     }
@@ -77,10 +53,10 @@ fn cksum(input: &[u8]) -> usize {
 
 fn fill_hole(
     pos: &mut usize,
-    mut l: &mut usize,
+    l: &mut usize,
     mut n_hole: u8,
     r: &mut usize,
-    mut n_at_r: &mut u8,
+    n_at_r: &mut u8,
     input: &[u8],
 ) -> usize {
     let mut sum = 0;
@@ -102,6 +78,20 @@ fn fill_hole(
     sum
 }
 
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        string_to_digits(input)
+    }
+
+    fn part1(disk_map: &Self::Input) -> impl Display {
+        cksum(disk_map)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -142,12 +132,11 @@ mod tests {
         let s = cksum(&input);
         assert_eq!(s, 1928);
     }
-}
 
-fn main() {
-    let bytes = include_bytes!("../input.txt");
-    let hd = string_to_digits(bytes);
-    dbg!(hd.len());
-    let s = cksum(&hd);
-    println!("{}", s);
+    #[test]
+    fn test_string_to_digits() {
+        assert_eq!(string_to_digits("12345").unwrap(), vec![1, 2, 3, 4, 5]);
+        let err = string_to_digits("123-45").unwrap_err();
+        assert_eq!(err, ParseError::new(1, 4, "a digit").in_day(9));
+    }
 }
@@ -1,17 +1,20 @@
-use core::str;
-use std::{collections::VecDeque, error, vec};
+use aoc_common::{Line, ParseError};
+use std::collections::VecDeque;
 
-fn parse_input(input: &[u8]) -> Result<VecDeque<u64>, Box<dyn error::Error>> {
-    let s = str::from_utf8(input)?;
-    let substrings: Vec<&str> = s.split_whitespace().collect();
-    let numbers: VecDeque<u64> = substrings
-        .into_iter()
-        .map(|substr| substr.parse())
-        .collect::<Result<VecDeque<u64>, _>>()?;
-    Ok(numbers)
+const DAY: u8 = 11;
+
+/// Parses the whitespace separated numbers engraved on the stones.
+pub fn parse_input(input: &str) -> Result<VecDeque<u64>, ParseError> {
+    Line::all(DAY, input)
+        .flat_map(|line| {
+            line.text
+                .split_whitespace()
+                .map(move |stone| line.parse(stone, "a number"))
+        })
+        .collect()
 }
 
-fn ndigits(x: u64) -> u64 {
+pub fn ndigits(x: u64) -> u64 {
     let mut n = 1;
     let mut x = x;
     while x > 9 {
@@ -21,23 +24,23 @@ fn ndigits(x: u64) -> u64 {
     n
 }
 
-fn split(x: u64) -> (u64, u64) {
+pub fn split(x: u64) -> (u64, u64) {
     let n = ndigits(x);
     let d = 10u64.pow((n / 2).try_into().unwrap());
     (x / d, x % d)
 }
 
-fn blink(x: u64) -> Vec<u64> {
+pub fn blink(x: u64) -> Vec<u64> {
     if x == 0 {
         return vec![1];
-    } else if ndigits(x) % 2 == 0 {
+    } else if ndigits(x).is_multiple_of(2) {
         let (a, b) = split(x);
         return vec![a, b];
     }
-    return vec![x * 2024];
+    vec![x * 2024]
 }
 
-fn blink_many(x: &VecDeque<u64>, k: u64) -> u64 {
+pub fn blink_many(x: &VecDeque<u64>, k: u64) -> u64 {
     let mut x = x.clone();
     for _ in 0..k {
         let xlen = x.len();
@@ -51,12 +54,6 @@ fn blink_many(x: &VecDeque<u64>, k: u64) -> u64 {
     x.len() as u64
 }
 
-fn main() {
-    let bytes = include_bytes!("../input.txt");
-    let numbers = parse_input(bytes).unwrap();
-    println!{"{}", blink_many(&numbers, 75)};
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,50 @@
+use aoc_common::{ParseError, Solution};
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Display;
+
+pub use day11a::{ndigits as n_digits, parse_input, split};
+
+pub fn blink_memo(x: u64, k: u64, memo: &mut HashMap<(u64, u64), u64>) -> u64 {
+    if let Some(&result) = memo.get(&(x, k)) {
+        return result;
+    }
+    let result = if k == 0 {
+        1
+    } else if x == 0 {
+        blink_memo(1, k - 1, memo)
+    } else if n_digits(x).is_multiple_of(2) {
+        let (a, b) = split(x);
+        blink_memo(a, k - 1, memo) + blink_memo(b, k - 1, memo)
+    } else {
+        blink_memo(x * 2024, k - 1, memo)
+    };
+    memo.insert((x, k), result);
+    result
+}
+
+pub fn blink_many(x: &VecDeque<u64>, k: u64) -> u64 {
+    let mut memo = HashMap::new();
+    let mut result = 0;
+    for &x in x.iter() {
+        result += blink_memo(x, k, &mut memo);
+    }
+    result
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = VecDeque<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(stones: &Self::Input) -> impl Display {
+        day11a::blink_many(stones, 25)
+    }
+
+    fn part2(stones: &Self::Input) -> Option<impl Display> {
+        Some(blink_many(stones, 75))
+    }
+}
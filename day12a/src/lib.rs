@@ -0,0 +1,42 @@
+use aoc_common::{Grid, Position};
+use std::collections::HashSet;
+
+fn perimeter_increase(grid: &Grid, pos: Position) -> usize {
+    4 - grid
+        .neighbours4(pos)
+        .filter(|&neighbour| grid[pos] == grid[neighbour])
+        .count()
+}
+
+fn measure_plot(grid: &Grid, pos: Position, visited: &mut HashSet<Position>) -> (usize, usize) {
+    visited.insert(pos);
+    let mut size = 1;
+    let mut perim = perimeter_increase(grid, pos);
+    for neighbour in grid.neighbours4(pos) {
+        if visited.contains(&neighbour) || grid[pos] != grid[neighbour] {
+            continue;
+        }
+        let (size_inc, perim_inc) = measure_plot(grid, neighbour, visited);
+        size += size_inc;
+        perim += perim_inc;
+    }
+    (size, perim)
+}
+
+pub fn measure_map(grid: &Grid) -> Vec<(usize, usize)> {
+    let mut visited = HashSet::new();
+    let mut result = Vec::new();
+    for pos in grid.positions() {
+        if visited.contains(&pos) {
+            continue;
+        }
+        let (size, perim) = measure_plot(grid, pos, &mut visited);
+        result.push((size, perim));
+    }
+    result
+}
+
+pub fn price_map(grid: &Grid) -> usize {
+    let measures = measure_map(grid);
+    measures.iter().map(|(size, perim)| size * perim).sum()
+}
@@ -1,67 +1,7 @@
-use core::str;
+use aoc_common::{Grid, ParseError, Solution};
 use std::collections::HashMap;
 use std::collections::HashSet;
-use std::fs;
-use std::io;
-use std::str::FromStr;
-
-struct Grid {
-    width: usize,
-    height: usize,
-    data: Vec<char>,
-}
-
-impl Grid {
-    fn new(width: usize, height: usize, data: Vec<char>) -> Grid {
-        Grid {
-            width,
-            height,
-            data,
-        }
-    }
-
-    fn new_from_vecs(width: usize, height: usize, data: Vec<Vec<char>>) -> Grid {
-        let data: Vec<char> = data.into_iter().flatten().collect();
-        Grid {
-            width,
-            height,
-            data,
-        }
-    }
-
-    fn adjacent(&self, x: usize, y: usize) -> Vec<(usize, usize)> {
-        let mut result = Vec::new();
-        if x > 0 {
-            result.push((x - 1, y));
-        }
-        if y > 0 {
-            result.push((x, y - 1));
-        }
-        if x < self.width - 1 {
-            result.push((x + 1, y));
-        }
-        if y < self.height - 1 {
-            result.push((x, y + 1));
-        }
-        result
-    }
-
-    fn get(&self, x: usize, y: usize) -> char {
-        self.data[y * self.width + x]
-    }
-}
-
-impl FromStr for Grid {
-    type Err = io::Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let lines: Vec<Vec<char>> = s.lines().map(|line| line.chars().collect()).collect();
-        let height = lines.len();
-        let width = lines[0].len();
-        let data: Vec<char> = lines.into_iter().flatten().collect();
-        Ok(Grid::new(width, height, data))
-    }
-}
+use std::fmt::Display;
 
 /// Measures the plots in the first row of a grid.
 ///
@@ -82,7 +22,7 @@ impl FromStr for Grid {
 /// * A vector of plot indices labeling each character in the row.
 /// * A hashmap of plot statistics mapping each plot index to a tuple of plot size and perimeter.
 ///
-fn measure_first_row(row: &[char]) -> (Vec<usize>, HashMap<usize, (usize, usize)>) {
+pub fn measure_first_row(row: &[char]) -> (Vec<usize>, HashMap<usize, (usize, usize)>) {
     let mut plot_idx = 0;
     let mut left = 0;
     let mut right = left;
@@ -102,27 +42,23 @@ fn measure_first_row(row: &[char]) -> (Vec<usize>, HashMap<usize, (usize, usize)
 }
 
 fn additional_perim_left(curr: &[char], prev: &[char], left: usize) -> usize {
-    if curr[left] != prev[left] {
-        return 2;
-    } else if left > 0 && curr[left] == prev[left - 1] {
+    if curr[left] != prev[left] || (left > 0 && curr[left] == prev[left - 1]) {
         return 2;
     }
-    return 0;
+    0
 }
 
 fn additional_perim_right(curr: &[char], prev: &[char], right: usize) -> usize {
-    if curr[right] != prev[right] {
-        return 2;
-    } else if right < curr.len() - 1 && curr[right] == prev[right + 1] {
+    if curr[right] != prev[right] || (right < curr.len() - 1 && curr[right] == prev[right + 1]) {
         return 2;
     }
-    return 0;
+    0
 }
 
 fn relabel(prev_plot_ids: &mut [usize], connected: &HashSet<usize>, new_id: usize) {
-    for i in 0..prev_plot_ids.len() {
-        if connected.contains(&prev_plot_ids[i]) {
-            prev_plot_ids[i] = new_id;
+    for plot_id in prev_plot_ids.iter_mut() {
+        if connected.contains(plot_id) {
+            *plot_id = new_id;
         }
     }
 }
@@ -214,10 +150,10 @@ fn calculate_discontinued_value(
 /// * Plot IDs for the current row.
 /// * Updated plot statistics.
 /// * Total value of discontinued plots.
-fn measure_row(
+pub fn measure_row(
     curr: &[char],
     prev: &[char],
-    prev_plot_ids: &mut Vec<usize>,
+    prev_plot_ids: &mut [usize],
     prev_plot_stats: &mut HashMap<usize, (usize, usize)>,
 ) -> (Vec<usize>, HashMap<usize, (usize, usize)>, usize) {
     let mut discontinued = prev_plot_ids.iter().cloned().collect::<HashSet<_>>();
@@ -229,7 +165,7 @@ fn measure_row(
     let mut unassigned_id = min_id;
     while left < curr.len() {
         let connected = gather_connected_plots(curr, prev, prev_plot_ids, left, &mut right);
-        let relabeled = connected.iter().filter(|&&pid| pid >= min_id).next();
+        let relabeled = connected.iter().find(|&&pid| pid >= min_id);
         let id = if let Some(&plot_id) = relabeled {
             plot_id
         } else {
@@ -237,9 +173,7 @@ fn measure_row(
             unassigned_id - 1
         };
         // Assign the plot id to the newly found rectangle
-        for i in left..right {
-            plot_ids[i] = id;
-        }
+        plot_ids[left..right].fill(id);
         // Update the connected rectangles in the row above
         relabel(prev_plot_ids, &connected, id);
         let (total_size, total_perim) =
@@ -255,13 +189,13 @@ fn measure_row(
     (plot_ids, plot_stats, discontinued_value)
 }
 
-fn price_map(grid: &Grid) -> usize {
+pub fn price_map(grid: &Grid) -> usize {
     let mut value = 0;
-    let (mut prev_plot_ids, mut prev_plot_stats) = measure_first_row(&grid.data[0..grid.width]);
+    let (mut prev_plot_ids, mut prev_plot_stats) = measure_first_row(grid.row(0));
     for y in 1..grid.height {
         let (plot_ids, plot_stats, discontinued) = measure_row(
-            &grid.data[y * grid.width..(y + 1) * grid.width],
-            &grid.data[(y - 1) * grid.width..y * grid.width],
+            grid.row(y),
+            grid.row(y - 1),
             &mut prev_plot_ids,
             &mut prev_plot_stats,
         );
@@ -276,9 +210,28 @@ fn price_map(grid: &Grid) -> usize {
     value + final_row_value
 }
 
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse().map_err(|err: ParseError| err.in_day(12))
+    }
+
+    fn part1(grid: &Self::Input) -> impl Display {
+        day12a::price_map(grid)
+    }
+
+    fn part2(grid: &Self::Input) -> Option<impl Display> {
+        Some(price_map(grid))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_common::input::puzzle_input;
 
     #[test]
     fn price_map_test_large() {
@@ -308,7 +261,7 @@ mod tests {
     }
 
     #[test]
-    fn price_map_test_E() {
+    fn price_map_test_e() {
         let rows = vec![
             vec!['E', 'E', 'E', 'E', 'E'],
             vec!['E', 'X', 'X', 'X', 'X'],
@@ -323,13 +276,10 @@ mod tests {
 
     #[test]
     fn price_map_test_from_file() {
-        // Read the input file
-        let input = fs::read_to_string("input.txt").expect("Failed to read input file");
-        // Parse the input into a grid
-        let rows: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
-        let width = rows[0].len();
-        let height = rows.len();
-        let g = Grid::new_from_vecs(width, height, rows);
+        let Some(input) = puzzle_input(12) else {
+            return;
+        };
+        let g: Grid = input.parse().unwrap();
         let price = price_map(&g);
         assert_eq!(price, 873584);
     }
@@ -471,10 +421,3 @@ mod tests {
         assert_eq!(plot_stats.get(&3), Some(&(1, 4)));
     }
 }
-
-fn main() {
-    let content = fs::read_to_string("input.txt").unwrap();
-    let grid: Grid = content.parse().unwrap();
-    let price = price_map(&grid);
-    println!("{:?}", price);
-}
@@ -0,0 +1,218 @@
+use aoc_common::{Direction, Grid, Line, ParseError};
+
+pub use aoc_common::Position;
+
+const DAY: u8 = 15;
+
+pub type Instruction = Direction;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Free,
+    Wall,
+    Box,
+}
+
+/// The warehouse map. The robot is not part of it, its cell is free.
+pub type Warehouse = Grid<Cell>;
+
+/// The cell at `pos`, the warehouse is surrounded by walls.
+fn cell(grid: &Warehouse, pos: Position) -> Cell {
+    grid.get(pos).copied().unwrap_or(Cell::Wall)
+}
+
+fn position_past_boxes(
+    grid: &Warehouse,
+    first_box: Position,
+    direction: Direction,
+) -> Option<Position> {
+    let mut current_pos = first_box;
+    loop {
+        let next_pos = direction.step(current_pos);
+        match cell(grid, next_pos) {
+            Cell::Box => current_pos = next_pos,
+            Cell::Wall => return None,
+            Cell::Free => return Some(next_pos),
+        }
+    }
+}
+
+fn total_boxes_gps(grid: &Warehouse) -> i64 {
+    grid.iter()
+        .filter(|(_, &cell)| cell == Cell::Box)
+        .map(|((x, y), _)| 100 * y + x)
+        .sum()
+}
+
+pub fn robot_step(robot: Position, instruction: Instruction, grid: &mut Warehouse) -> Position {
+    let new_pos = instruction.step(robot);
+    match cell(grid, new_pos) {
+        Cell::Free => new_pos,
+        Cell::Box => {
+            if let Some(shove_to) = position_past_boxes(grid, new_pos, instruction) {
+                grid[new_pos] = Cell::Free;
+                grid[shove_to] = Cell::Box;
+                new_pos
+            } else {
+                robot
+            }
+        }
+        Cell::Wall => robot,
+    }
+}
+
+pub fn robot_walk(robot: Position, instructions: &[Instruction], grid: &mut Warehouse) -> Position {
+    let mut current_pos = robot;
+    for &instruction in instructions {
+        current_pos = robot_step(current_pos, instruction, grid);
+    }
+    current_pos
+}
+
+pub fn total_gps_after_walk(
+    robot: Position,
+    instructions: &[Instruction],
+    grid: &mut Warehouse,
+) -> i64 {
+    robot_walk(robot, instructions, grid);
+    total_boxes_gps(grid)
+}
+
+/// Parses the warehouse map, the robot's position and its moves. The map is
+/// separated from the moves, which may span several lines, by a blank line.
+pub fn parse_input(input: &str) -> Result<(Warehouse, Position, Vec<Instruction>), ParseError> {
+    let mut lines = Line::all(DAY, input);
+    let map: Vec<&str> = lines
+        .by_ref()
+        .map(|line| line.text)
+        .take_while(|text| !text.is_empty())
+        .collect();
+
+    let mut robots = 0;
+    let grid = Grid::parse_with(
+        &map.join("\n"),
+        "`#`, `O`, `.` or a single `@`",
+        |ch| match ch {
+            '#' | 'O' | '.' => Some(ch),
+            '@' if robots == 0 => {
+                robots += 1;
+                Some(ch)
+            }
+            _ => None,
+        },
+    )
+    .map_err(|err| err.in_day(DAY))?;
+    let robot = grid
+        .position(|&ch| ch == '@')
+        .ok_or_else(|| ParseError::new(map.len() + 1, 1, "a robot `@` in the map").in_day(DAY))?;
+    let grid = grid.map(|&ch| match ch {
+        '#' => Cell::Wall,
+        'O' => Cell::Box,
+        _ => Cell::Free,
+    });
+
+    let mut instructions = Vec::new();
+    for line in lines {
+        for (x, ch) in line.text.chars().enumerate() {
+            match Direction::from_arrow(ch) {
+                Some(instruction) => instructions.push(instruction),
+                None => return Err(line.error_at_column(x + 1, "a move `^`, `v`, `<` or `>`")),
+            }
+        }
+    }
+
+    Ok((grid, robot, instructions))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn cells(grid: &Warehouse, kind: Cell) -> HashSet<Position> {
+        grid.iter()
+            .filter(|&(_, &cell)| cell == kind)
+            .map(|(pos, _)| pos)
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let input = "\
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<";
+
+        let (grid, robot, instructions) = parse_input(input).unwrap();
+
+        assert_eq!(grid.width, 8);
+        assert_eq!(grid.height, 8);
+        assert_eq!(robot, (2, 2));
+        assert_eq!(grid[(0, 0)], Cell::Wall);
+        assert_eq!(grid[(7, 7)], Cell::Wall);
+        assert_eq!(grid[robot], Cell::Free);
+
+        let mut expected_walls = HashSet::new();
+        for x in 0..8 {
+            expected_walls.insert((x, 0));
+            expected_walls.insert((x, 7));
+        }
+        for y in 0..8 {
+            expected_walls.insert((0, y));
+            expected_walls.insert((7, y));
+        }
+        expected_walls.insert((1, 2));
+        expected_walls.insert((2, 4));
+        assert_eq!(cells(&grid, Cell::Wall), expected_walls);
+
+        let expected_boxes: HashSet<Position> =
+            vec![(3, 1), (5, 1), (4, 2), (4, 3), (4, 4), (4, 5)]
+                .into_iter()
+                .collect();
+        assert_eq!(cells(&grid, Cell::Box), expected_boxes);
+        assert_eq!(instructions.len(), 15);
+        assert_eq!(instructions[0], Direction::West);
+        assert_eq!(instructions[1], Direction::North);
+        assert_eq!(instructions[2], Direction::North);
+        assert_eq!(instructions[3], Direction::East);
+        assert_eq!(instructions[4], Direction::East);
+        assert_eq!(instructions[5], Direction::East);
+        assert_eq!(instructions[6], Direction::South);
+        assert_eq!(instructions[7], Direction::South);
+        assert_eq!(instructions[8], Direction::West);
+        assert_eq!(instructions[9], Direction::South);
+        assert_eq!(instructions[10], Direction::East);
+        assert_eq!(instructions[11], Direction::East);
+        assert_eq!(instructions[12], Direction::South);
+        assert_eq!(instructions[13], Direction::West);
+        assert_eq!(instructions[14], Direction::West);
+    }
+
+    #[test]
+    fn test_parse_input_errors() {
+        let err = parse_input("####\n#@x#\n####\n\n<>").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(2, 3, "`#`, `O`, `.` or a single `@`").in_day(15)
+        );
+        let err = parse_input("####\n#..#\n####\n\n<>").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(4, 1, "a robot `@` in the map").in_day(15)
+        );
+        let err = parse_input("####\n#@.#\n####\n\n<>\n^x").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(6, 2, "a move `^`, `v`, `<` or `>`").in_day(15)
+        );
+    }
+
+    // ...existing code...
+}
@@ -1,64 +1,47 @@
+use aoc_common::{Direction, Grid, ParseError, Solution};
+use day15a::Warehouse;
 use std::collections::HashSet;
-use std::fmt;
+use std::fmt::{self, Display};
 
-type Position = (i64, i64);
-type Instruction = (i64, i64);
+pub use aoc_common::Position;
 
-#[derive(Debug, PartialEq)]
-enum Cell {
+pub type Instruction = Direction;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
     Free,
     Wall,
     // the position of the left half of the box
     Box(Position),
 }
 
-struct Grid {
-    width: i64,
-    height: i64,
-    walls: HashSet<Position>,
-    boxes: HashSet<Position>,
-    robot: Position,
+pub struct WideWarehouse {
+    pub map: Grid<Cell>,
+    pub robot: Position,
 }
 
-impl Grid {
-    fn new(
-        width: i64,
-        height: i64,
-        walls: HashSet<Position>,
-        boxes: HashSet<Position>,
-        robot: Position,
-    ) -> Self {
-        Grid {
-            width,
-            height,
-            walls,
-            boxes,
-            robot,
-        }
+impl WideWarehouse {
+    fn new(map: Grid<Cell>, robot: Position) -> Self {
+        WideWarehouse { map, robot }
     }
 
     /// Get the content of the cell at position `pos`.
     fn get(&self, pos: Position) -> Cell {
-        if self.walls.contains(&pos) {
-            Cell::Wall
-        } else if self.boxes.contains(&pos) {
-            Cell::Box(pos)
-        } else {
-            // either free or right half of a box
-            if self.boxes.contains(&(pos.0 - 1, pos.1)) {
-                Cell::Box((pos.0 - 1, pos.1))
-            } else {
-                Cell::Free
-            }
-        }
+        // the warehouse is surrounded by walls
+        self.map.get(pos).copied().unwrap_or(Cell::Wall)
+    }
+
+    /// Whether the left half of a box is at position `pos`.
+    fn is_box(&self, pos: Position) -> bool {
+        self.get(pos) == Cell::Box(pos)
     }
 
     /// Get boxes that are horizontally connected to the `first_box` when moving in `direction`.
-    fn h_connected(&self, first_box: Position, direction: (i64, i64)) -> HashSet<Position> {
+    fn h_connected(&self, first_box: Position, direction: Direction) -> HashSet<Position> {
         let mut current_pos = first_box;
         let mut connected = HashSet::new();
-        let step = (2 * direction.0, 0);
-        while self.boxes.contains(&current_pos) {
+        let step = (2 * direction.delta().0, 0);
+        while self.is_box(current_pos) {
             connected.insert(current_pos);
             current_pos = (current_pos.0 + step.0, current_pos.1);
         }
@@ -70,12 +53,12 @@ impl Grid {
     fn v_adjacent(&self, box_pos: Position, v_offset: i64) -> Vec<Position> {
         (-1..=1)
             .map(|dx| (box_pos.0 + dx, box_pos.1 + v_offset))
-            .filter(|next_pos| self.boxes.contains(next_pos))
+            .filter(|&next_pos| self.is_box(next_pos))
             .collect()
     }
 
     /// Get boxes that are vertically connected to the `first_box`.
-    fn v_connected(&self, first_box: Position, direction: (i64, i64)) -> HashSet<Position> {
+    fn v_connected(&self, first_box: Position, direction: Direction) -> HashSet<Position> {
         let mut level_set = HashSet::new();
         level_set.insert(first_box);
         let mut next_level = HashSet::new();
@@ -83,7 +66,7 @@ impl Grid {
         while !level_set.is_empty() {
             for box_pos in level_set.iter() {
                 connected.insert(*box_pos);
-                for b in self.v_adjacent(*box_pos, direction.1) {
+                for b in self.v_adjacent(*box_pos, direction.delta().1) {
                     next_level.insert(b);
                 }
             }
@@ -94,60 +77,68 @@ impl Grid {
     }
 
     /// Get boxes that are connected to the `first_box` when moving in `direction`.
-    fn connected_boxes(&self, first_box: Position, direction: (i64, i64)) -> HashSet<Position> {
-        if direction == (-1, 0) || direction == (1, 0) {
-            return self.h_connected(first_box, direction);
+    fn connected_boxes(&self, first_box: Position, direction: Direction) -> HashSet<Position> {
+        if direction.is_horizontal() {
+            self.h_connected(first_box, direction)
         } else {
-            return self.v_connected(first_box, direction);
+            self.v_connected(first_box, direction)
         }
     }
 
     /// There must not be a wall in the direction of the shove.
     /// A box is ok because we will in turn check that that box can be shoved.
-    fn shovable(&self, box_pos: Position, direction: (i64, i64)) -> bool {
+    fn shovable(&self, box_pos: Position, direction: Direction) -> bool {
         // ensure no wall
-        let next_l = (box_pos.0 + direction.0, box_pos.1 + direction.1);
-        let next_r = (box_pos.0 + 1 + direction.0, box_pos.1 + direction.1);
-        return !self.walls.contains(&next_l) && !self.walls.contains(&next_r);
+        let next_l = direction.step(box_pos);
+        let next_r = direction.step((box_pos.0 + 1, box_pos.1));
+        self.get(next_l) != Cell::Wall && self.get(next_r) != Cell::Wall
+    }
+
+    /// Put both halves of a box whose left half is at `box_pos`, or clear them.
+    fn set_box(&mut self, box_pos: Position, cell: Cell) {
+        self.map[box_pos] = cell;
+        self.map[(box_pos.0 + 1, box_pos.1)] = cell;
     }
 
     /// Get the total GPS of all boxes.
     fn total_boxes_gps(&self) -> i64 {
-        self.boxes.iter().map(|(x, y)| 100 * y + x).sum()
+        self.map
+            .iter()
+            .filter(|&(pos, &cell)| cell == Cell::Box(pos))
+            .map(|((x, y), _)| 100 * y + x)
+            .sum()
     }
 }
 
-impl fmt::Display for Grid {
+impl fmt::Display for WideWarehouse {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for y in 0..self.height {
-            for x in 0..self.width {
-                let pos = (x, y);
-                if pos == self.robot {
-                    write!(f, "@")?;
-                } else {
-                    match self.get(pos) {
-                        Cell::Free => write!(f, ".")?,
-                        Cell::Wall => write!(f, "#")?,
-                        Cell::Box(left_half) => {
-                            if pos == left_half {
-                                write!(f, "[")?;
-                            } else {
-                                write!(f, "]")?;
-                            }
+        for (pos, &cell) in self.map.iter() {
+            if pos == self.robot {
+                write!(f, "@")?;
+            } else {
+                match cell {
+                    Cell::Free => write!(f, ".")?,
+                    Cell::Wall => write!(f, "#")?,
+                    Cell::Box(left_half) => {
+                        if pos == left_half {
+                            write!(f, "[")?;
+                        } else {
+                            write!(f, "]")?;
                         }
                     }
                 }
             }
-            writeln!(f)?;
+            if pos.0 as usize == self.map.width - 1 {
+                writeln!(f)?;
+            }
         }
         Ok(())
     }
 }
 
 /// Move the robot one step according to the instruction and update the grid.
-fn robot_step(robot: Position, instruction: Instruction, grid: &mut Grid) -> Position {
-    let nboxes = grid.boxes.len();
-    let new_pos = (robot.0 + instruction.0, robot.1 + instruction.1);
+pub fn robot_step(robot: Position, instruction: Instruction, grid: &mut WideWarehouse) -> Position {
+    let new_pos = instruction.step(robot);
     match grid.get(new_pos) {
         Cell::Free => new_pos,
         Cell::Box(box_pos) => {
@@ -156,16 +147,15 @@ fn robot_step(robot: Position, instruction: Instruction, grid: &mut Grid) -> Pos
                 .iter()
                 .all(|&b| grid.shovable(b, instruction))
             {
-                // get the position of the shoved boxes
-                let shoved_boxes = connected_boxes
-                    .iter()
-                    .map(|&b| (b.0 + instruction.0, b.1 + instruction.1));
-                // remove all connected boxes from grid.boxes
+                // remove all connected boxes from the map
                 for &b in connected_boxes.iter() {
-                    grid.boxes.remove(&b);
+                    grid.set_box(b, Cell::Free);
                 }
                 // insert the shoved boxes
-                grid.boxes.extend(shoved_boxes);
+                for &b in connected_boxes.iter() {
+                    let shoved = instruction.step(b);
+                    grid.set_box(shoved, Cell::Box(shoved));
+                }
                 new_pos
             } else {
                 robot
@@ -176,7 +166,11 @@ fn robot_step(robot: Position, instruction: Instruction, grid: &mut Grid) -> Pos
 }
 
 /// Move the robot according to the instructions and update the grid.
-fn robot_walk(robot: Position, instructions: &[Instruction], grid: &mut Grid) -> Position {
+pub fn robot_walk(
+    robot: Position,
+    instructions: &[Instruction],
+    grid: &mut WideWarehouse,
+) -> Position {
     let mut current_pos = robot;
     for &instruction in instructions {
         current_pos = robot_step(current_pos, instruction, grid);
@@ -186,69 +180,55 @@ fn robot_walk(robot: Position, instructions: &[Instruction], grid: &mut Grid) ->
 }
 
 /// Calculate the total GPS after the robot has walked according to the instructions.
-fn total_gps_after_walk(robot: Position, instructions: &[Instruction], grid: &mut Grid) -> i64 {
+pub fn total_gps_after_walk(
+    robot: Position,
+    instructions: &[Instruction],
+    grid: &mut WideWarehouse,
+) -> i64 {
     robot_walk(robot, instructions, grid);
     grid.total_boxes_gps()
 }
 
-/// Parse the input string into a Grid, robot position, and instructions.
-fn parse_input(input: &str) -> (Grid, Position, Vec<Instruction>) {
-    let parts: Vec<&str> = input.split("\n\n").collect();
-    let grid_lines: Vec<&str> = parts[0].lines().collect();
-    let instructions_str = parts[1].trim();
-
-    let height = grid_lines.len() as i64;
-    let width = 2 * grid_lines[0].len() as i64;
-
-    let mut robot = (0, 0);
-    let mut obstacles = HashSet::new();
-    let mut boxes = HashSet::new();
-
-    for (y, line) in grid_lines.iter().enumerate() {
-        let mut x = 0;
-        for (_, ch) in line.chars().enumerate() {
-            let pos = (x, y as i64);
-            match ch {
-                '#' => {
-                    obstacles.insert(pos);
-                    obstacles.insert((x + 1, y as i64));
-                }
-                // O becomes [], implemented by get
-                'O' => {
-                    boxes.insert(pos);
-                }
-                // @ becomes @.
-                '@' => {
-                    robot = (x, y as i64);
-                }
-                _ => {}
-            };
-            x += 2;
-        }
-    }
-
-    let instructions = instructions_str
-        .chars()
-        .map(|ch| match ch {
-            '^' => (0, -1),
-            'v' => (0, 1),
-            '<' => (-1, 0),
-            '>' => (1, 0),
-            _ => (0, 0),
+/// Widen the grid of part a: every tile becomes two tiles wide.
+/// `#` becomes `##`, `O` becomes `[]` and `@` becomes `@.`.
+pub fn widen(grid: &Warehouse, robot: Position) -> (WideWarehouse, Position) {
+    let cells = grid
+        .iter()
+        .flat_map(|((x, y), cell)| match cell {
+            day15a::Cell::Free => [Cell::Free; 2],
+            day15a::Cell::Wall => [Cell::Wall; 2],
+            day15a::Cell::Box => [Cell::Box((2 * x, y)); 2],
         })
         .collect();
+    let map = Grid::new(2 * grid.width, grid.height, cells);
+    let robot = (2 * robot.0, robot.1);
+    (WideWarehouse::new(map, robot), robot)
+}
 
-    (
-        Grid::new(width, height, obstacles, boxes, robot),
-        robot,
-        instructions,
-    )
+/// Parse the input string into a Grid, robot position, and instructions.
+pub fn parse_input(input: &str) -> Result<(WideWarehouse, Position, Vec<Instruction>), ParseError> {
+    let (grid, robot, instructions) = day15a::parse_input(input)?;
+    let (grid, robot) = widen(&grid, robot);
+    Ok((grid, robot, instructions))
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").expect("Failed to read input file");
-    let (mut grid, robot, instructions) = parse_input(&input);
-    println!("{}", total_gps_after_walk(robot, &instructions, &mut grid));
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = (Warehouse, Position, Vec<Instruction>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        day15a::parse_input(input)
+    }
+
+    fn part1((grid, robot, instructions): &Self::Input) -> impl Display {
+        day15a::total_gps_after_walk(*robot, instructions, &mut grid.clone())
+    }
+
+    fn part2((grid, robot, instructions): &Self::Input) -> Option<impl Display> {
+        let (mut grid, robot) = widen(grid, *robot);
+        Some(total_gps_after_walk(robot, instructions, &mut grid))
+    }
 }
 
 #[cfg(test)]
@@ -269,7 +249,7 @@ mod tests {
 
 <^^>>>vv<v>>v<<";
 
-        let (grid, _, _instructions) = parse_input(input);
+        let (grid, _, _instructions) = parse_input(input).unwrap();
         let expected_output = "\
 ################
 ##....[]..[]..##
@@ -299,7 +279,7 @@ mod tests {
 
 <vv>^<v^>v>";
 
-        let (grid, robot, _instructions) = parse_input(input);
+        let (grid, _robot, _instructions) = parse_input(input).unwrap();
         let expected_output = "\
 ####################
 ##....[]....[]..[]##
@@ -330,10 +310,10 @@ mod tests {
 
 ^
 ";
-        let (mut grid, robot, _instructions) = parse_input(input);
+        let (mut grid, robot, _instructions) = parse_input(input).unwrap();
         println!("{}", grid);
-        let robot = robot_step(robot, (1, 0), &mut grid);
-        let robot = robot_step(robot, (1, 0), &mut grid);
+        let robot = robot_step(robot, Direction::East, &mut grid);
+        let robot = robot_step(robot, Direction::East, &mut grid);
         grid.robot = robot;
         //println!("{}", grid);
         assert_eq!(robot, (6, 5));
@@ -352,7 +332,7 @@ mod tests {
 ########
 
 <^^>>>vv<v>>v<<";
-        let (mut grid, robot, _instructions) = parse_input(input);
+        let (mut grid, robot, _instructions) = parse_input(input).unwrap();
         assert_eq!(total_gps_after_walk(robot, &_instructions, &mut grid), 1751);
     }
 
@@ -380,7 +360,7 @@ vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
 <><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
 ^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
 v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
-        let (mut grid, robot, _instructions) = parse_input(input);
+        let (mut grid, robot, _instructions) = parse_input(input).unwrap();
         println!("{}", grid);
         assert_eq!(total_gps_after_walk(robot, &_instructions, &mut grid), 9021);
     }
@@ -397,10 +377,10 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
 #######
 
 <vv<<^^<<^^";
-        let (mut grid, robot, _instructions) = parse_input(input);
+        let (mut grid, robot, _instructions) = parse_input(input).unwrap();
         assert_eq!(
             total_gps_after_walk(robot, &_instructions, &mut grid),
-            100 * 1 + 5 + 100 * 2 + 7 + 100 * 3 + 6
+            100 + 5 + 100 * 2 + 7 + 100 * 3 + 6
         );
     }
 }
@@ -0,0 +1,202 @@
+use aoc_common::{BitGrid, Direction, Grid, ParseError, Solution};
+use priority_queue::PriorityQueue;
+use std::cmp::Reverse;
+use std::fmt::{self, Display};
+
+pub use aoc_common::Position;
+
+const DAY: u8 = 16;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct State {
+    pub position: Position,
+    pub orientation: Direction,
+}
+
+impl State {
+    fn new(position: Position, orientation: Direction) -> Self {
+        State {
+            position,
+            orientation,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Free,
+    Wall,
+}
+
+#[derive(Debug)]
+pub struct Maze {
+    pub map: Grid<Cell>,
+    pub start: Position,
+    pub end: Position,
+}
+
+impl Maze {
+    fn new(map: Grid<Cell>, start: Position, end: Position) -> Self {
+        Maze { map, start, end }
+    }
+
+    /// Get the content of the cell at position `pos`.
+    fn get(&self, pos: Position) -> Cell {
+        // the maze is surrounded by walls
+        self.map.get(pos).copied().unwrap_or(Cell::Wall)
+    }
+}
+
+impl fmt::Display for Maze {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cells = self.map.iter().map(|(pos, cell)| {
+            if pos == self.start {
+                'S'
+            } else if pos == self.end {
+                'E'
+            } else {
+                match cell {
+                    Cell::Free => '.',
+                    Cell::Wall => '#',
+                }
+            }
+        });
+        let map = Grid::new(self.map.width, self.map.height, cells.collect());
+        write!(f, "{}", map)
+    }
+}
+
+/// Parse the input string into a Maze, the start and the end position.
+pub fn parse_input(input: &str) -> Result<(Maze, Position, Position), ParseError> {
+    let mut starts = 0;
+    let mut ends = 0;
+    let map = Grid::parse_with(input, "`#`, `.`, or a single `S` and `E`", |ch| match ch {
+        '#' | '.' => Some(ch),
+        'S' if starts == 0 => {
+            starts += 1;
+            Some(ch)
+        }
+        'E' if ends == 0 => {
+            ends += 1;
+            Some(ch)
+        }
+        _ => None,
+    })
+    .map_err(|err| err.in_day(DAY))?;
+    let missing = |what| ParseError::new(map.height + 1, 1, what).in_day(DAY);
+    let start = map
+        .position(|&ch| ch == 'S')
+        .ok_or_else(|| missing("a start position `S`"))?;
+    let end = map
+        .position(|&ch| ch == 'E')
+        .ok_or_else(|| missing("an end position `E`"))?;
+    let map = map.map(|&ch| if ch == '#' { Cell::Wall } else { Cell::Free });
+    Ok((Maze::new(map, start, end), start, end))
+}
+
+pub fn all_orientations(s: State, cost: i64) -> Vec<(State, i64)> {
+    vec![
+        (State::new(s.position, s.orientation), cost),
+        (
+            State::new(s.position, s.orientation.turn_right()),
+            cost + 1000,
+        ),
+        (
+            State::new(s.position, s.orientation.turn_left()),
+            cost + 1000,
+        ),
+        (State::new(s.position, s.orientation.reverse()), cost + 2000),
+    ]
+}
+
+/// find shortest path from start to end
+/// return -1 if no path is found
+pub fn shortest_path_score(grid: &Maze, start: Position, end: Position) -> i64 {
+    let mut pq = PriorityQueue::new();
+    all_orientations(State::new(start, Direction::East), 0)
+        .into_iter()
+        .for_each(|(s, c)| {
+            pq.push(s, Reverse(c));
+        });
+    // one set of visited positions per orientation
+    let mut visited = vec![BitGrid::new(grid.map.width, grid.map.height); 4];
+    let is_visited =
+        |visited: &[BitGrid], s: &State| visited[s.orientation as usize].contains(s.position);
+    while let Some((state, Reverse(cost))) = pq.pop() {
+        if state.position == end {
+            return cost;
+        }
+        if is_visited(&visited, &state) {
+            continue;
+        }
+        visited[state.orientation as usize].insert(state.position);
+        let new_pos = state.orientation.step(state.position);
+        if grid.get(new_pos) == Cell::Wall {
+            continue;
+        }
+        let new_states = all_orientations(State::new(new_pos, state.orientation), 1);
+        new_states
+            .iter()
+            .filter(|(s, _)| !is_visited(&visited, s))
+            .for_each(|(s, c)| {
+                pq.push(s.clone(), Reverse(cost + c));
+            });
+    }
+    -1
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = (Maze, Position, Position);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((grid, start, end): &Self::Input) -> impl Display {
+        shortest_path_score(grid, *start, *end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_display_grid() {
+        let input = "\
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############";
+
+        let (grid, _start, _end) = parse_input(input).unwrap();
+        assert_eq!(grid.map.width, 15);
+        assert_eq!(grid.map.height, 15);
+        // check that fmt output is the same as input
+        assert_eq!(format!("{}", grid), input);
+    }
+
+    #[test]
+    fn test_parse_input_errors() {
+        let err = parse_input("####\n#SE#\n#.x#\n####").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(3, 3, "`#`, `.`, or a single `S` and `E`").in_day(16)
+        );
+        let err = parse_input("####\n#S.#\n####").unwrap_err();
+        assert_eq!(err, ParseError::new(4, 1, "an end position `E`").in_day(16));
+    }
+}